@@ -9,13 +9,191 @@
 This Rust crate that provides efficient 2D vector and rectangle operations using fixed-point arithmetic.
 Designed for applications where fixed precision is preferred, this crate is ideal for scenarios such as graphics programming,
 game development, and embedded systems where deterministic results are crucial.
+
+The crate only needs `core` and `alloc`. Disable the default `std` feature to use it in
+`no_std` environments.
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
-use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 use fixed32::Fp;
 
+#[cfg(feature = "serde")]
+mod serde_support;
+
+/// Number of fractional bits in the raw representation of an [`Fp`].
+const FRACTIONAL_BITS: u32 = Fp::one().inner().trailing_zeros();
+
+/// Computes the integer square root of `value`, rounded down, one bit at a time.
+const fn sqrt_u64(value: u64) -> u64 {
+    let mut remainder = value;
+    let mut root = 0;
+    let mut bit = 1 << 62;
+    while bit > remainder {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+/// Intersects a ray with the slab between `min` and `max` along one axis.
+///
+/// Returns the entry and exit `t` (or `None` for an unbounded side when the ray is parallel to
+/// the slab) and the outward normal of the entry face, where `min_normal` is the normal of the
+/// face at `min`. Returns `None` if the ray is parallel to the slab and outside of it.
+fn slab(
+    origin: Fp,
+    direction: Fp,
+    min: Fp,
+    max: Fp,
+    min_normal: Vector,
+) -> Option<(Option<Fp>, Option<Fp>, Vector)> {
+    if direction.is_zero() {
+        return if origin < min || origin > max {
+            None
+        } else {
+            Some((None, None, min_normal))
+        };
+    }
+    let to_min = (min - origin) / direction;
+    let to_max = (max - origin) / direction;
+    if direction > Fp::zero() {
+        Some((Some(to_min), Some(to_max), min_normal))
+    } else {
+        Some((Some(to_max), Some(to_min), -min_normal))
+    }
+}
+
+/// Rounds `values` down to whole units and then rounds up the ones with the largest fractional
+/// parts, so that the total matches the rounded sum of the original values.
+fn round_preserving_sum(values: impl Iterator<Item = Fp>) -> Vec<Fp> {
+    let mut rounded: Vec<Fp> = Vec::new();
+    let mut remainders = Vec::new();
+    let mut sum = Fp::zero();
+    let mut rounded_sum = Fp::zero();
+    for (index, value) in values.enumerate() {
+        let floor = value.floor();
+        rounded.push(floor);
+        remainders.push((value - floor, index));
+        sum += value;
+        rounded_sum += floor;
+    }
+
+    let missing = (sum.round() - rounded_sum).inner() >> FRACTIONAL_BITS;
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for &(_, index) in remainders.iter().take(missing.max(0) as usize) {
+        rounded[index] += Fp::one();
+    }
+    rounded
+}
+
+/// Computes `e^-x` for a non-negative `x` (negative values are treated as zero).
+///
+/// The integer part is handled by repeated multiplication with `e^-1` and the fractional part
+/// with a truncated Taylor series, so only deterministic fixed-point operations are used.
+fn exp_neg(x: Fp) -> Fp {
+    // e^-1 in 16.16 fixed-point.
+    const E_NEG_ONE: Fp = Fp::from_raw(24109);
+    const TAYLOR_TERMS: i16 = 10;
+
+    let x = Fp::max(x, Fp::zero());
+    let whole = x.inner() >> FRACTIONAL_BITS;
+    if whole > 11 {
+        // e^-12 is below the smallest representable positive value.
+        return Fp::zero();
+    }
+    let fraction = x - Fp::from(whole as i16);
+
+    let mut term = Fp::one();
+    let mut sum = Fp::one();
+    for k in 1..=TAYLOR_TERMS {
+        term = -term * fraction / Fp::from(k);
+        sum += term;
+    }
+    for _ in 0..whole {
+        sum = sum * E_NEG_ONE;
+    }
+    sum
+}
+
+/// Computes the four-quadrant arctangent of `y / x` in radians, in the range `(-π, π]`.
+///
+/// The ratio is first reduced to `-1..=1` by swapping the arguments when needed, and the
+/// arctangent of the reduced ratio is approximated with a minimax polynomial that has a maximum
+/// error of about `0.0001` radians.
+fn atan2(y: Fp, x: Fp) -> Fp {
+    const PI: Fp = Fp::from_raw(Fp::FRAC_PI_2.inner() * 2);
+    // Coefficients of the odd polynomial, in 16.16 fixed-point.
+    const COEFFICIENTS: [Fp; 5] = [
+        Fp::from_raw(65527),
+        Fp::from_raw(-21647),
+        Fp::from_raw(11806),
+        Fp::from_raw(-5579),
+        Fp::from_raw(1365),
+    ];
+
+    let atan = |z: Fp| {
+        let z2 = z * z;
+        let polynomial = COEFFICIENTS
+            .iter()
+            .rev()
+            .fold(Fp::zero(), |sum, coefficient| sum * z2 + *coefficient);
+        z * polynomial
+    };
+
+    if x.is_zero() && y.is_zero() {
+        Fp::zero()
+    } else if x.abs() >= y.abs() {
+        let angle = atan(y / x);
+        if x > Fp::zero() {
+            angle
+        } else if y >= Fp::zero() {
+            angle + PI
+        } else {
+            angle - PI
+        }
+    } else {
+        let angle = atan(x / y);
+        if y > Fp::zero() {
+            Fp::FRAC_PI_2 - angle
+        } else {
+            -Fp::FRAC_PI_2 - angle
+        }
+    }
+}
+
+/// Re-exports the commonly used types, so they can be imported with a single `use`.
+///
+/// ```
+/// use fixed32_math::prelude::*;
+///
+/// let rect = Rect::new(Vector::from((0, 0)), Vector::from((4, 2)));
+/// assert_eq!(rect.area(), Fp::from(8));
+/// ```
+pub mod prelude {
+    pub use crate::{Easing, Edge, IntVector, PointClass, Ray, Rect, Size, Vector};
+    pub use fixed32::Fp;
+}
+
 /// Represents a vector in a 2D space.
 ///
 #[derive(Default, PartialEq, Eq, Clone, Copy)]
@@ -50,6 +228,96 @@ impl Vector {
         Self { x, y }
     }
 
+    /// Creates a new `Vector` from integer components.
+    ///
+    /// Unlike the `From<(i16, i16)>` conversion, this is a `const fn`, so it can be used to build
+    /// constant tables of vectors. Both components must be in the range of an [`i16`],
+    /// `-32768..=32767`, which is the integer range of [`Fp`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either component is out of range. In a `const` context, this is a compile-time
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32_math::Vector;
+    ///
+    /// const NEIGHBORS: [Vector; 4] = [
+    ///     Vector::from_ints(1, 0),
+    ///     Vector::from_ints(0, 1),
+    ///     Vector::from_ints(-1, 0),
+    ///     Vector::from_ints(0, -1),
+    /// ];
+    /// assert_eq!(NEIGHBORS[2], Vector::left());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_ints(x: i32, y: i32) -> Self {
+        assert!(
+            x >= i16::MIN as i32
+                && x <= i16::MAX as i32
+                && y >= i16::MIN as i32
+                && y <= i16::MAX as i32,
+            "Vector::from_ints components must be in the i16 range"
+        );
+        Self {
+            x: Fp::from_raw(x << FRACTIONAL_BITS),
+            y: Fp::from_raw(y << FRACTIONAL_BITS),
+        }
+    }
+
+    /// Returns a copy of the vector with the `x` component replaced.
+    #[inline]
+    #[must_use]
+    pub const fn with_x(&self, x: Fp) -> Self {
+        Self { x, y: self.y }
+    }
+
+    /// Returns a copy of the vector with the `y` component replaced.
+    #[inline]
+    #[must_use]
+    pub const fn with_y(&self, y: Fp) -> Self {
+        Self { x: self.x, y }
+    }
+
+    /// Returns a mutable reference to the `x` component.
+    #[inline]
+    pub fn x_mut(&mut self) -> &mut Fp {
+        &mut self.x
+    }
+
+    /// Returns a mutable reference to the `y` component.
+    #[inline]
+    pub fn y_mut(&mut self) -> &mut Fp {
+        &mut self.y
+    }
+
+    /// Returns the component for the given axis, where `0` is `x` and `1` is `y`.
+    ///
+    /// # Panics
+    /// Panics if `axis` is not `0` or `1`.
+    #[inline]
+    #[must_use]
+    pub fn axis(&self, axis: usize) -> Fp {
+        self[axis]
+    }
+
+    /// Returns a mutable reference to the component for the given axis, where `0` is `x` and
+    /// `1` is `y`.
+    ///
+    /// # Panics
+    /// Panics if `axis` is not `0` or `1`.
+    #[inline]
+    pub fn axis_mut(&mut self, axis: usize) -> &mut Fp {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("vector axis {axis} is out of range, expected 0 or 1"),
+        }
+    }
+
     /// Returns a `Vector` pointing to the left (negative x-axis direction).
     ///
     /// This is a convenience method to create a vector that represents a direction
@@ -188,6 +456,41 @@ impl Vector {
         self.sqr_len().sqrt()
     }
 
+    /// Computes the distance between this point and `other`.
+    #[must_use]
+    pub fn distance(&self, other: &Self) -> Fp {
+        self.sqr_distance(other).sqrt()
+    }
+
+    /// Computes the squared distance between this point and `other`.
+    ///
+    /// Prefer this over [`Self::distance`] when only comparing distances, as it avoids the
+    /// square root.
+    #[must_use]
+    pub fn sqr_distance(&self, other: &Self) -> Fp {
+        (*self - *other).sqr_len()
+    }
+
+    /// Checks if the length of the vector is at most `epsilon`.
+    ///
+    /// Useful for treating tiny residuals, for example after subtracting two nearly equal
+    /// positions, as zero. The comparison is done on the squared length, so no square root is
+    /// needed.
+    #[must_use]
+    pub fn approx_zero(&self, epsilon: Fp) -> bool {
+        self.sqr_len() <= epsilon * epsilon
+    }
+
+    /// Checks if each component differs from the matching component of `other` by at most
+    /// `epsilon`.
+    ///
+    /// Useful for comparing against expected values after a chain of divisions, where exact
+    /// fixed-point equality can fail by a few raw units.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: Fp) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
     /// Returns a normalized vector with length 1. Returns `None` if the vector is zero-length.
     #[must_use]
     pub fn normalize(&self) -> Option<Self> {
@@ -202,18 +505,175 @@ impl Vector {
         }
     }
 
+    /// Returns a normalized vector with length 1, computed with a fixed integer algorithm.
+    /// Returns `None` if the vector is zero-length.
+    ///
+    /// The length is the integer square root (rounded down) of the sum of the squared raw
+    /// components, computed in 64 bits. Each raw component is then shifted up by the number of
+    /// fractional bits and divided by that length, truncating towards zero. Since the algorithm
+    /// does not depend on the implementation of [`Fp::sqrt`], the result is bit-identical on
+    /// every platform and build, which lockstep simulations can rely on.
+    #[must_use]
+    pub fn normalize_fast(&self) -> Option<Self> {
+        let sqr_len = i64::from(self.x.inner()).unsigned_abs().pow(2)
+            + i64::from(self.y.inner()).unsigned_abs().pow(2);
+        let length = sqrt_u64(sqr_len) as i64;
+        if length == 0 {
+            return None;
+        }
+        let normalize = |value: Fp| {
+            Fp::from_raw(((i64::from(value.inner()) << FRACTIONAL_BITS) / length) as i32)
+        };
+        Some(Self {
+            x: normalize(self.x),
+            y: normalize(self.y),
+        })
+    }
+
+    /// Returns the vector scaled so that `|x| + |y| == 1`, projecting it onto the L1 unit diamond.
+    /// Returns `None` if the vector is zero-length.
+    ///
+    /// Unlike [`Self::normalize`] this does not need a square root, which makes it a cheap choice
+    /// for grid movement where diagonal and cardinal directions should cover the same distance.
+    #[must_use]
+    pub fn normalize_l1(&self) -> Option<Self> {
+        let length = self.x.abs() + self.y.abs();
+        if length.is_zero() {
+            None
+        } else {
+            Some(Self {
+                x: self.x / length,
+                y: self.y / length,
+            })
+        }
+    }
+
+    /// Returns the normalized direction from this point towards `target`.
+    /// Returns `None` if the two points coincide.
+    #[must_use]
+    pub fn direction_to(&self, target: &Self) -> Option<Self> {
+        (*target - *self).normalize()
+    }
+
+    /// Returns the angle of the vector in radians, measured counter-clockwise from the positive
+    /// x-axis, in the range `(-π, π]`. The angle of a zero-length vector is zero.
+    #[must_use]
+    pub fn angle(&self) -> Fp {
+        atan2(self.y, self.x)
+    }
+
+    /// Returns which of `sectors` equal angular sectors the vector points into, counting
+    /// counter-clockwise from `0`.
+    ///
+    /// Sector `0` is centered on the positive x-axis, so with eight sectors a vector pointing
+    /// slightly below `+x` is still in sector `0`, and straight up is sector `2`. This matches
+    /// picking one of several directional sprite frames. A zero-length vector is in sector `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sectors` is zero.
+    #[must_use]
+    pub fn angle_sector(&self, sectors: u32) -> u32 {
+        assert!(sectors > 0, "angle_sector needs at least one sector");
+        let full_turn = i64::from(Fp::FRAC_PI_2.inner()) * 4;
+        let mut angle = i64::from(self.angle().inner());
+        if angle < 0 {
+            angle += full_turn;
+        }
+        let sectors = i64::from(sectors);
+        // Shifted by half a sector so that each sector is centered on its direction.
+        let sector = (angle * sectors * 2 + full_turn) / (full_turn * 2);
+        (sector % sectors) as u32
+    }
+
+    /// Returns the signed angle in radians to turn this vector towards `other`, in the range
+    /// `(-π, π]`. The angle is positive when `other` is counter-clockwise from this vector.
+    #[must_use]
+    pub fn angle_between(&self, other: &Self) -> Fp {
+        atan2(self.cross(other), self.dot(other))
+    }
+
     /// Computes the dot product of this vector with another.
     #[must_use]
     pub fn dot(&self, other: &Self) -> Fp {
         self.x * other.x + self.y * other.y
     }
 
+    /// Computes the component-wise (Hadamard) product `(x * other.x, y * other.y)`.
+    ///
+    /// This is what `a * b` does for two vectors. For the scalar product, use [`Self::dot`].
+    #[must_use]
+    pub fn hadamard(&self, other: &Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+
     /// Computes the magnitude of the cross product in 2D (which is a scalar value).
     #[must_use]
     pub fn cross(&self, other: &Self) -> Fp {
         self.x * other.y - self.y * other.x
     }
 
+    /// Returns the sign of the [`Self::cross`] product: `1` if `other` is counter-clockwise
+    /// from this vector, `-1` if it is clockwise and `0` if they are parallel.
+    ///
+    /// The cross product is computed on the raw fixed-point values in a wider integer type, so
+    /// the sign is exact and never affected by rounding or overflow.
+    #[must_use]
+    pub fn cross_sign(&self, other: &Self) -> i8 {
+        let cross = i128::from(self.x.inner()) * i128::from(other.y.inner())
+            - i128::from(self.y.inner()) * i128::from(other.x.inner());
+        cross.signum() as i8
+    }
+
+    /// Checks if this vector is clockwise from `other` around the origin, that is, if turning
+    /// from `other` to this vector by less than half a turn goes clockwise.
+    ///
+    /// Parallel and opposite vectors are not clockwise from each other. The check only uses the
+    /// exact sign of the cross product, see [`Self::cross_sign`], so no trigonometry is needed.
+    #[must_use]
+    pub fn is_clockwise_from(&self, other: &Self) -> bool {
+        self.cross_sign(other) > 0
+    }
+
+    /// Sorts `points` counter-clockwise by their angle around `center`.
+    ///
+    /// The order starts at the direction of the positive x-axis (angle zero) and goes
+    /// counter-clockwise up to, but not including, a full turn. Points at the same angle are
+    /// ordered by increasing distance from `center`, and points equal to `center` come first.
+    /// Only exact cross-product signs are used, so no trigonometry is needed and the result is
+    /// deterministic.
+    pub fn sort_by_angle(points: &mut [Self], center: Self) {
+        let half_plane = |v: &Self| {
+            if v.x.is_zero() && v.y.is_zero() {
+                0
+            } else if v.y > Fp::zero() || (v.y.is_zero() && v.x > Fp::zero()) {
+                1
+            } else {
+                2
+            }
+        };
+        points.sort_by(|a, b| {
+            let a = *a - center;
+            let b = *b - center;
+            half_plane(&a)
+                .cmp(&half_plane(&b))
+                .then_with(|| b.cross_sign(&a).cmp(&0))
+                .then_with(|| a.sqr_len().cmp(&b.sqr_len()))
+        });
+    }
+
+    /// Computes the signed area of the triangle formed by the origin, this vector and `other`.
+    ///
+    /// This is half of the [`Self::cross`] product. The area is positive if `other` is
+    /// counter-clockwise from this vector and negative if it is clockwise.
+    #[must_use]
+    pub fn signed_area_swept(&self, other: &Self) -> Fp {
+        self.cross(other) / Fp::from(2)
+    }
+
     /// Scales the vector by another vector component-wise.
     #[must_use]
     pub fn scale(&self, factor: &Self) -> Self {
@@ -234,99 +694,786 @@ impl Vector {
         }
     }
 
-    /// Returns the absolute value of each component of the vector.
+    /// Constrains the direction of the vector to the cone of `max_half_angle` radians on
+    /// either side of `axis`, keeping its length.
+    ///
+    /// Vectors inside the cone are returned unchanged. Vectors outside it are rotated back by
+    /// the smallest amount that puts them on the nearest boundary of the cone. Zero-length
+    /// vectors, and any vector when `axis` is zero-length, are returned unchanged. A negative
+    /// `max_half_angle` is treated as zero.
     #[must_use]
-    pub const fn abs(&self) -> Self {
-        Self {
-            x: self.x.abs(),
-            y: self.y.abs(),
+    pub fn clamp_to_cone(&self, axis: &Self, max_half_angle: Fp) -> Self {
+        if *self == Self::default() || *axis == Self::default() {
+            return *self;
+        }
+        let max_half_angle = Fp::max(max_half_angle, Fp::zero());
+        let angle = axis.angle_between(self);
+        if angle > max_half_angle {
+            self.rotate(max_half_angle - angle)
+        } else if angle < -max_half_angle {
+            self.rotate(-max_half_angle - angle)
+        } else {
+            *self
         }
     }
-}
-
-impl fmt::Debug for Vector {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "vec:{},{}", self.x, self.y)
-    }
-}
-
-impl fmt::Display for Vector {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "vec:{},{}", self.x, self.y)
-    }
-}
 
-impl From<(i16, i16)> for Vector {
-    fn from(values: (i16, i16)) -> Self {
+    /// Returns the vector rotated 90 degrees counter-clockwise, `(-y, x)`.
+    ///
+    /// Unlike [`Self::rotate`], this only swaps and negates components, so it is exact.
+    #[must_use]
+    pub fn perpendicular(&self) -> Self {
         Self {
-            x: Fp::from(values.0),
-            y: Fp::from(values.1),
+            x: -self.y,
+            y: self.x,
         }
     }
-}
 
-impl From<(f32, f32)> for Vector {
-    fn from(values: (f32, f32)) -> Self {
+    /// Returns the vector rotated 90 degrees clockwise, `(y, -x)`.
+    ///
+    /// Unlike [`Self::rotate`], this only swaps and negates components, so it is exact.
+    #[must_use]
+    pub fn perpendicular_cw(&self) -> Self {
         Self {
-            x: Fp::from(values.0),
-            y: Fp::from(values.1),
+            x: self.y,
+            y: -self.x,
         }
     }
-}
 
-impl Sub for Vector {
-    type Output = Self;
+    /// Creates a velocity from a compass heading in degrees and a scalar `speed`.
+    ///
+    /// This uses the navigation convention, not the mathematical one: a heading of `0` points up
+    /// (north, `+y`) and headings increase clockwise, so `90` points right (east, `+x`). The
+    /// result is `(sin(heading), cos(heading)) * speed`.
+    #[must_use]
+    pub fn from_heading_speed(heading_degrees: Fp, speed: Fp) -> Self {
+        let heading = heading_degrees.to_radians();
+        Self::new(heading.sin() * speed, heading.cos() * speed)
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+    /// Returns the right and up axes of a frame rotated by the given angle in radians.
+    ///
+    /// The right axis is `(cos, sin)` and the up axis is `(-sin, cos)`. Both are computed from
+    /// a single evaluation of the sine and cosine, so they are orthogonal and consistent.
+    #[must_use]
+    pub fn basis_from_angle(angle: Fp) -> (Self, Self) {
+        let cos_angle = angle.cos();
+        let sin_angle = angle.sin();
+        (
+            Self::new(cos_angle, sin_angle),
+            Self::new(-sin_angle, cos_angle),
+        )
     }
-}
 
-impl Add for Vector {
-    type Output = Self;
+    /// Returns a closure that rotates vectors by the given angle in radians.
+    ///
+    /// The sine and cosine are computed once, up front, which makes this a cheap way to rotate
+    /// many vectors by the same angle, for example in an iterator pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32::Fp;
+    /// use fixed32_math::Vector;
+    ///
+    /// let points = [Vector::from((1, 0)), Vector::from((0, 2))];
+    /// let rotated: Vec<Vector> = points.iter().copied().map(Vector::rotator(Fp::zero())).collect();
+    /// assert_eq!(rotated, points);
+    /// ```
+    pub fn rotator(angle: Fp) -> impl Fn(Self) -> Self {
+        let cos_angle = angle.cos();
+        let sin_angle = angle.sin();
+        move |vector| Self {
+            x: vector.x * cos_angle - vector.y * sin_angle,
+            y: vector.x * sin_angle + vector.y * cos_angle,
+        }
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
+    /// Projects this vector onto `other`, computing `other * (self.dot(other) / other.sqr_len())`.
+    /// Returns `None` if `other` is zero-length.
+    ///
+    /// The dot product and squared length are computed exactly in 128-bit integers and the
+    /// division is done last, so long vectors do not overflow and only the final result is
+    /// rounded.
+    #[must_use]
+    pub fn project_onto(&self, other: &Self) -> Option<Self> {
+        let sqr_len = other.wide_dot(other);
+        if sqr_len == 0 {
+            None
+        } else {
+            Some(other.scaled_by_ratio(self.wide_dot(other), sqr_len))
         }
     }
-}
 
-impl AddAssign for Vector {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
+    /// Computes the dot product exactly from the raw values, with twice the fractional bits of an
+    /// [`Fp`].
+    fn wide_dot(&self, other: &Self) -> i128 {
+        i128::from(self.x.inner()) * i128::from(other.x.inner())
+            + i128::from(self.y.inner()) * i128::from(other.y.inner())
     }
-}
 
-impl Mul<Self> for Vector {
-    type Output = Self;
+    /// Scales the vector by `numerator / denominator`, multiplying before dividing.
+    fn scaled_by_ratio(&self, numerator: i128, denominator: i128) -> Self {
+        let scale =
+            |value: Fp| Fp::from_raw((i128::from(value.inner()) * numerator / denominator) as i32);
+        Self::new(scale(self.x), scale(self.y))
+    }
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x * rhs.x,
-            y: self.y * rhs.y,
-        }
+    /// Returns the part of this vector that is perpendicular to `other`, which is the vector
+    /// minus its [projection](Self::project_onto). Returns `None` if `other` is zero-length.
+    #[must_use]
+    pub fn reject_from(&self, other: &Self) -> Option<Self> {
+        self.project_onto(other)
+            .map(|projection| *self - projection)
     }
-}
 
-impl Mul<Vector> for Fp {
-    type Output = Vector;
+    /// Removes the component of this vector along `dir`, keeping only the motion perpendicular
+    /// to it. `dir` does not need to be normalized.
+    ///
+    /// This is [`Self::reject_from`] for constraint solving, where a degenerate constraint
+    /// direction should not block motion: if `dir` is zero-length, the vector is returned
+    /// unchanged.
+    #[must_use]
+    pub fn remove_component_along(&self, dir: &Self) -> Self {
+        self.reject_from(dir).unwrap_or(*self)
+    }
 
-    fn mul(self, rhs: Vector) -> Self::Output {
-        Vector {
-            x: self * rhs.x,
-            y: self * rhs.y,
-        }
+    /// Reflects `velocity` off the line through `seg_a` and `seg_b`, as when bouncing off a wall.
+    ///
+    /// The component along the segment is kept and the component perpendicular to it is flipped.
+    /// The normal is derived from the segment direction, so no normalization (and no square root)
+    /// is needed. A zero-length segment returns `velocity` unchanged.
+    #[must_use]
+    pub fn reflect_off_segment(velocity: &Self, seg_a: &Self, seg_b: &Self) -> Self {
+        velocity
+            .project_onto(&(*seg_b - *seg_a))
+            .map_or(*velocity, |along| along * Fp::from(2) - *velocity)
     }
-}
 
-impl Mul<Fp> for Vector {
-    type Output = Self;
+    /// Compares two vectors lexicographically, first by `x` and then by `y`.
+    ///
+    /// Since [`Fp`] has no NaN, this is a true total order over all representable vectors and
+    /// gives the same result on every platform, which makes it suitable for sorting and as the
+    /// key order of ordered collections.
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
+    }
+
+    /// Finds the point on the segment between `a` and `b` that is closest to this point.
+    ///
+    /// Returns the closest point together with a flag that is `true` if the projection onto the
+    /// line fell outside the segment and was clamped to one of the endpoints. If `a` and `b`
+    /// coincide, `a` is returned and the flag is `true`.
+    #[must_use]
+    pub fn closest_point_on_segment_info(&self, a: &Self, b: &Self) -> (Self, bool) {
+        let segment = *b - *a;
+        let sqr_len = segment.wide_dot(&segment);
+        let t = (*self - *a).wide_dot(&segment);
+        if sqr_len == 0 || t < 0 {
+            (*a, true)
+        } else if t > sqr_len {
+            (*b, true)
+        } else {
+            (*a + segment.scaled_by_ratio(t, sqr_len), false)
+        }
+    }
+
+    /// Computes the squared distance from this point to the segment between `a` and `b`.
+    ///
+    /// The point is projected onto the segment and the projection is clamped to the endpoints.
+    /// The projection is computed in 128-bit integers, so long segments do not overflow. No
+    /// square root is taken, so this is cheap to use when ranking many segments by proximity.
+    /// If `a` and `b` coincide, the squared distance to that point is returned.
+    #[must_use]
+    pub fn distance_to_segment_squared(&self, a: &Self, b: &Self) -> Fp {
+        let (closest, _) = self.closest_point_on_segment_info(a, b);
+        (*self - closest).sqr_len()
+    }
+
+    /// Moves the point onto the nearest edge of `rect`.
+    ///
+    /// A point inside the rectangle is moved perpendicularly onto the closest of the four edges.
+    /// When it is equally close to several edges, the first one in the order left, right,
+    /// bottom, top is chosen. A point outside the rectangle is clamped onto the border.
+    #[must_use]
+    pub fn snap_to_nearest_edge(&self, rect: &Rect) -> Self {
+        let clamped = Self {
+            x: Fp::min(Fp::max(self.x, rect.left()), rect.right()),
+            y: Fp::min(Fp::max(self.y, rect.bottom()), rect.top()),
+        };
+        if clamped != *self {
+            return clamped;
+        }
+
+        let to_left = self.x - rect.left();
+        let to_right = rect.right() - self.x;
+        let to_bottom = self.y - rect.bottom();
+        let to_top = rect.top() - self.y;
+        let nearest = to_left.min(to_right).min(to_bottom).min(to_top);
+
+        if nearest == to_left {
+            Self::new(rect.left(), self.y)
+        } else if nearest == to_right {
+            Self::new(rect.right(), self.y)
+        } else if nearest == to_bottom {
+            Self::new(self.x, rect.bottom())
+        } else {
+            Self::new(self.x, rect.top())
+        }
+    }
+
+    /// Returns the position of the `index`-th cell in a row-major grid that is `width` cells wide,
+    /// where each cell has the size `cell`.
+    ///
+    /// Returns `None` if `width` is zero, or if the column or row of the cell does not fit in an
+    /// `i16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32_math::Vector;
+    ///
+    /// let position = Vector::from_index(7, 3, Vector::from((16, 8))).unwrap();
+    /// assert_eq!(position, Vector::from((16, 16)));
+    /// assert_eq!(position.to_index(3, Vector::from((16, 8))), Some(7));
+    /// ```
+    #[must_use]
+    pub fn from_index(index: usize, width: usize, cell: Self) -> Option<Self> {
+        let column = i16::try_from(index.checked_rem(width)?).ok()?;
+        let row = i16::try_from(index / width).ok()?;
+        Some(Self {
+            x: cell.x * Fp::from(column),
+            y: cell.y * Fp::from(row),
+        })
+    }
+
+    /// Returns the index of the cell containing this position in a row-major grid that is `width`
+    /// cells wide, where each cell has the size `cell`. This is the inverse of [`Self::from_index`].
+    ///
+    /// Returns `None` if either component of `cell` is zero, if the position is to the left of or
+    /// below the origin or to the right of the last column, or if the index overflows `usize`.
+    #[must_use]
+    pub fn to_index(&self, width: usize, cell: Self) -> Option<usize> {
+        if cell.x.is_zero() || cell.y.is_zero() {
+            return None;
+        }
+        let column = usize::try_from((self.x / cell.x).inner() >> FRACTIONAL_BITS).ok()?;
+        let row = usize::try_from((self.y / cell.y).inner() >> FRACTIONAL_BITS).ok()?;
+        if column >= width {
+            return None;
+        }
+        row.checked_mul(width)?.checked_add(column)
+    }
+
+    /// Reflects the vector across a surface with the given `normal`, as when bouncing off it.
+    ///
+    /// Computes `self - 2 * self.dot(normal) * normal`. `normal` must have unit length for the
+    /// result to keep the length of the vector. Since normalization in fixed-point is lossy, a
+    /// normal that is only approximately unit length gives an approximate reflection.
+    #[must_use]
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (Fp::from(2) * self.dot(normal))
+    }
+
+    /// Returns the part of the vector that is tangent to a surface with the given `normal`.
+    ///
+    /// This is the "slide along the wall" collision response: the component into the surface is
+    /// removed and the rest of the motion is kept. `normal` is expected to have unit length.
+    #[must_use]
+    pub fn slide(&self, normal: &Self) -> Self {
+        *self - *normal * self.dot(normal)
+    }
+
+    /// Multiplies each component by `2^bits` using a bit shift on the fixed-point representation.
+    ///
+    /// The shift is exact as long as the result fits: bits shifted out at the top are lost, so
+    /// large values wrap around, and shifting by 32 or more bits yields zero.
+    #[must_use]
+    pub fn shl(&self, bits: u32) -> Self {
+        let shift = |value: Fp| {
+            if bits >= 32 {
+                Fp::zero()
+            } else {
+                Fp::from_raw(value.inner() << bits)
+            }
+        };
+        Self {
+            x: shift(self.x),
+            y: shift(self.y),
+        }
+    }
+
+    /// Divides each component by `2^bits` using a bit shift on the fixed-point representation.
+    ///
+    /// The result is rounded towards negative infinity, so fractional bits shifted out at the
+    /// bottom are lost. Shifting by 31 or more bits yields zero for non-negative components and
+    /// the negative value closest to zero for negative components.
+    #[must_use]
+    pub fn shr(&self, bits: u32) -> Self {
+        let shift = |value: Fp| Fp::from_raw(value.inner() >> bits.min(31));
+        Self {
+            x: shift(self.x),
+            y: shift(self.y),
+        }
+    }
+
+    /// Linearly remaps each component from the range `in_min..in_max` to `out_min..out_max`.
+    ///
+    /// Values outside the input range are extrapolated. If the input range is empty on an axis,
+    /// that component maps to the corresponding component of `out_min`.
+    #[must_use]
+    pub fn remap(&self, in_min: Self, in_max: Self, out_min: Self, out_max: Self) -> Self {
+        let remap = |value: Fp, in_min: Fp, in_max: Fp, out_min: Fp, out_max: Fp| {
+            let in_range = in_max - in_min;
+            if in_range.is_zero() {
+                out_min
+            } else {
+                out_min + (value - in_min) * (out_max - out_min) / in_range
+            }
+        };
+        Self {
+            x: remap(self.x, in_min.x, in_max.x, out_min.x, out_max.x),
+            y: remap(self.y, in_min.y, in_max.y, out_min.y, out_max.y),
+        }
+    }
+
+    /// Rounds each vector to whole units while keeping the total as close as possible to the
+    /// sum of the original vectors.
+    ///
+    /// Each axis is handled separately with the largest remainder method: all components are
+    /// first rounded down, then the components with the largest fractional parts are rounded up
+    /// until the total equals the rounded sum of the originals. Ties are broken in favor of the
+    /// earlier vector, so the result is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32_math::Vector;
+    ///
+    /// let widths = [Vector::from((3.4, 0.0)), Vector::from((3.3, 0.0)), Vector::from((3.3, 0.0))];
+    /// let rounded = Vector::round_preserving_sum(&widths);
+    /// assert_eq!(rounded, [Vector::from((4, 0)), Vector::from((3, 0)), Vector::from((3, 0))]);
+    /// ```
+    #[must_use]
+    pub fn round_preserving_sum(values: &[Self]) -> Vec<Self> {
+        let xs = round_preserving_sum(values.iter().map(|value| value.x));
+        let ys = round_preserving_sum(values.iter().map(|value| value.y));
+        xs.into_iter().zip(ys).map(|(x, y)| Self { x, y }).collect()
+    }
+
+    /// Computes the center of mass of two point masses, the average of `a` and `b` weighted by
+    /// `mass_a` and `mass_b`.
+    ///
+    /// The weighted sums are accumulated in 128-bit integers on the raw fixed-point values, so
+    /// large masses do not overflow. If the total mass is zero, the midpoint is returned.
+    #[must_use]
+    pub fn center_of_mass(a: &Self, mass_a: Fp, b: &Self, mass_b: Fp) -> Self {
+        let total_mass = i128::from(mass_a.inner()) + i128::from(mass_b.inner());
+        if total_mass == 0 {
+            return (*a + *b) / 2;
+        }
+        let weighted = |value_a: Fp, value_b: Fp| {
+            let sum = i128::from(value_a.inner()) * i128::from(mass_a.inner())
+                + i128::from(value_b.inner()) * i128::from(mass_b.inner());
+            Fp::from_raw((sum / total_mass) as i32)
+        };
+        Self {
+            x: weighted(a.x, b.x),
+            y: weighted(a.y, b.y),
+        }
+    }
+
+    /// Converts barycentric coordinates `(u, v)` within the triangle `a`, `b`, `c` back to a
+    /// position, computing `a * (1 - u - v) + b * u + c * v`.
+    ///
+    /// `u = v = 0` returns `a`, `u = 1` returns `b` and `v = 1` returns `c`.
+    #[must_use]
+    pub fn from_barycentric(a: &Self, b: &Self, c: &Self, u: Fp, v: Fp) -> Self {
+        *a + (*b - *a) * u + (*c - *a) * v
+    }
+
+    /// Linearly interpolates between this vector and `target`, computing
+    /// `self + (target - self) * t`.
+    ///
+    /// `t` of zero returns `self` and `t` of one returns `target`, both exactly. Values of `t`
+    /// outside `0..=1` are not clamped and extrapolate beyond the two vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32::Fp;
+    /// use fixed32_math::Vector;
+    ///
+    /// let a = Vector::from((0, 10));
+    /// let b = Vector::from((4, 20));
+    /// assert_eq!(a.lerp(&b, Fp::from(0.5)), Vector::from((2, 15)));
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, target: &Self, t: Fp) -> Self {
+        if t.is_zero() {
+            *self
+        } else if t == Fp::one() {
+            *target
+        } else {
+            *self + (*target - *self) * t
+        }
+    }
+
+    /// Smoothly moves this vector towards `target` with exponential decay, computing
+    /// `self.lerp(target, 1 - exp(-lambda * dt))`.
+    ///
+    /// Unlike a fixed per-frame [`Self::lerp`], the result only depends on the elapsed time, not
+    /// on how it is split into frames, which makes the smoothing frame-rate independent. A
+    /// higher `lambda` converges faster. The exponential is evaluated in fixed-point with a fixed
+    /// algorithm, so the result is deterministic. A negative `lambda * dt` is treated as zero and
+    /// returns `self` unchanged.
+    #[must_use]
+    pub fn damp(&self, target: &Self, lambda: Fp, dt: Fp) -> Self {
+        self.lerp(target, Fp::one() - exp_neg(lambda * dt))
+    }
+
+    /// Moves this point towards `target` by at most `max_delta`.
+    ///
+    /// If `target` is within `max_delta`, it is returned exactly, so repeated calls settle on the
+    /// target instead of overshooting and oscillating around it. A zero or negative `max_delta`
+    /// returns `self` unchanged.
+    #[must_use]
+    pub fn move_towards(&self, target: &Self, max_delta: Fp) -> Self {
+        if max_delta <= Fp::zero() {
+            return *self;
+        }
+        let delta = *target - *self;
+        let distance = delta.len();
+        if distance <= max_delta {
+            *target
+        } else {
+            *self + delta.scaled_to_length(distance, max_delta)
+        }
+    }
+
+    /// Interpolates between this vector and `other`, shaping `t` with the given `easing` curve
+    /// before interpolating linearly.
+    ///
+    /// All curves map `t` of zero to `self` and `t` of one to `other`.
+    #[must_use]
+    pub fn ease_lerp(&self, other: &Self, t: Fp, easing: Easing) -> Self {
+        self.lerp(other, easing.apply(t))
+    }
+
+    /// Clamps the vector to lie within the axis-aligned ellipse with the given `radii`,
+    /// scaling it down along its own direction if it is outside.
+    ///
+    /// This is the anisotropic counterpart to clamping the length, useful when the maximum
+    /// speed differs per axis. If either radius is zero or negative, the ellipse degenerates
+    /// and each component is clamped into `-radius..=radius` on its own (zero for a non-positive
+    /// radius).
+    #[must_use]
+    pub fn clamp_to_ellipse(&self, radii: Self) -> Self {
+        if radii.x <= Fp::zero() || radii.y <= Fp::zero() {
+            let clamp = |value: Fp, radius: Fp| {
+                let radius = Fp::max(radius, Fp::zero());
+                Fp::min(Fp::max(value, -radius), radius)
+            };
+            return Self::new(clamp(self.x, radii.x), clamp(self.y, radii.y));
+        }
+        let scaled = Self::new(self.x / radii.x, self.y / radii.y);
+        let sqr_len = scaled.sqr_len();
+        if sqr_len <= Fp::one() {
+            *self
+        } else {
+            *self / sqr_len.sqrt()
+        }
+    }
+
+    /// Limits the length of the vector to at most `max`, keeping its direction.
+    ///
+    /// Vectors that are already short enough, and zero-length vectors, are returned unchanged.
+    #[must_use]
+    pub fn clamp_length_max(&self, max: Fp) -> Self {
+        let length = self.len();
+        if length.is_zero() || length <= max {
+            *self
+        } else {
+            self.scaled_to_length(length, max)
+        }
+    }
+
+    /// Limits the length of the vector to the range `min..=max`, keeping its direction.
+    ///
+    /// Zero-length vectors are returned unchanged, since they have no direction to scale along.
+    #[must_use]
+    pub fn clamp_length(&self, min: Fp, max: Fp) -> Self {
+        let length = self.len();
+        if length.is_zero() {
+            *self
+        } else if length < min {
+            self.scaled_to_length(length, min)
+        } else if length > max {
+            self.scaled_to_length(length, max)
+        } else {
+            *self
+        }
+    }
+
+    fn scaled_to_length(&self, length: Fp, target: Fp) -> Self {
+        Self::new(self.x * target / length, self.y * target / length)
+    }
+
+    /// Returns the component-wise minimum of this vector and `other`.
+    #[must_use]
+    pub fn min(&self, other: &Self) -> Self {
+        Self::new(Fp::min(self.x, other.x), Fp::min(self.y, other.y))
+    }
+
+    /// Returns the component-wise maximum of this vector and `other`.
+    #[must_use]
+    pub fn max(&self, other: &Self) -> Self {
+        Self::new(Fp::max(self.x, other.x), Fp::max(self.y, other.y))
+    }
+
+    /// Returns the smaller of the two components.
+    #[must_use]
+    pub fn min_component(&self) -> Fp {
+        Fp::min(self.x, self.y)
+    }
+
+    /// Returns the larger of the two components.
+    #[must_use]
+    pub fn max_component(&self) -> Fp {
+        Fp::max(self.x, self.y)
+    }
+
+    /// Clamps each component into the range given by the matching components of `min` and
+    /// `max`, constraining the point to the box between the two corners.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `min` is greater than `max` on either axis.
+    #[must_use]
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        debug_assert!(
+            min.x <= max.x && min.y <= max.y,
+            "clamp min {min} must not be greater than max {max}"
+        );
+        self.max(min).min(max)
+    }
+
+    /// Divides each component by the matching component of `divisor`, rounding the quotient
+    /// towards negative infinity.
+    ///
+    /// Unlike truncating division, this keeps cells the same size on both sides of zero, so a
+    /// point just left of the origin lands in cell `-1` instead of cell `0`. The division is done
+    /// on the raw fixed-point values, so the result is exact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either component of `divisor` is zero.
+    #[must_use]
+    pub fn floor_div(&self, divisor: Self) -> IntVector {
+        let floor_div = |value: Fp, divisor: Fp| {
+            let value = i64::from(value.inner());
+            let divisor = i64::from(divisor.inner());
+            let quotient = value / divisor;
+            if value % divisor != 0 && (value < 0) != (divisor < 0) {
+                (quotient - 1) as i32
+            } else {
+                quotient as i32
+            }
+        };
+        IntVector::new(floor_div(self.x, divisor.x), floor_div(self.y, divisor.y))
+    }
+
+    /// Returns the vector with each component rounded down to a whole unit.
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        Self::new(self.x.floor(), self.y.floor())
+    }
+
+    /// Returns the vector with each component rounded up to a whole unit.
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil())
+    }
+
+    /// Returns the vector with each component rounded to the nearest whole unit, with halves
+    /// rounded away from zero as [`Fp::round`] does.
+    #[must_use]
+    pub fn round(&self) -> Self {
+        Self::new(self.x.round(), self.y.round())
+    }
+
+    /// Returns the sign of each component: `-1` for negative, `0` for zero and `1` for positive.
+    #[must_use]
+    pub fn sign(&self) -> Self {
+        let sign = |value: Fp| match value.cmp(&Fp::zero()) {
+            Ordering::Less => Fp::neg_one(),
+            Ordering::Equal => Fp::zero(),
+            Ordering::Greater => Fp::one(),
+        };
+        Self::new(sign(self.x), sign(self.y))
+    }
+
+    /// Returns the absolute value of each component of the vector.
+    #[must_use]
+    pub const fn abs(&self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+}
+
+impl fmt::Debug for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vec:{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vec:{},{}", self.x, self.y)
+    }
+}
+
+impl From<(i16, i16)> for Vector {
+    fn from(values: (i16, i16)) -> Self {
+        Self {
+            x: Fp::from(values.0),
+            y: Fp::from(values.1),
+        }
+    }
+}
+
+impl From<(f32, f32)> for Vector {
+    fn from(values: (f32, f32)) -> Self {
+        Self {
+            x: Fp::from(values.0),
+            y: Fp::from(values.1),
+        }
+    }
+}
+
+impl Index<usize> for Vector {
+    type Output = Fp;
+
+    fn index(&self, axis: usize) -> &Fp {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("vector axis {axis} is out of range, expected 0 or 1"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector {
+    fn index_mut(&mut self, axis: usize) -> &mut Fp {
+        self.axis_mut(axis)
+    }
+}
+
+impl From<[Fp; 2]> for Vector {
+    fn from(values: [Fp; 2]) -> Self {
+        Self {
+            x: values[0],
+            y: values[1],
+        }
+    }
+}
+
+impl From<Vector> for [Fp; 2] {
+    fn from(vector: Vector) -> Self {
+        [vector.x, vector.y]
+    }
+}
+
+impl From<Vector> for (Fp, Fp) {
+    fn from(vector: Vector) -> Self {
+        (vector.x, vector.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<Self> for Vector {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Fp> for Vector {
+    fn mul_assign(&mut self, rhs: Fp) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<Self> for Vector {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<Fp> for Vector {
+    fn div_assign(&mut self, rhs: Fp) {
+        *self = *self / rhs;
+    }
+}
+
+/// Multiplies two vectors component-wise, which is the Hadamard product and not the dot or
+/// cross product. See [`Vector::hadamard`], [`Vector::dot`] and [`Vector::cross`].
+impl Mul<Self> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.hadamard(&rhs)
+    }
+}
+
+impl Mul<Vector> for Fp {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        Vector {
+            x: self * rhs.x,
+            y: self * rhs.y,
+        }
+    }
+}
+
+impl Mul<Fp> for Vector {
+    type Output = Self;
 
     fn mul(self, rhs: Fp) -> Self::Output {
         Self {
@@ -347,72 +1494,227 @@ impl Div<Self> for Vector {
     }
 }
 
-impl Div<Vector> for i16 {
-    type Output = Vector;
+impl Div<Vector> for i16 {
+    type Output = Vector;
+
+    fn div(self, rhs: Vector) -> Self::Output {
+        Vector {
+            x: (self / rhs.x),
+            y: (self / rhs.y),
+        }
+    }
+}
+
+impl Div<Fp> for Vector {
+    type Output = Self;
+
+    fn div(self, rhs: Fp) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl Div<i16> for Vector {
+    type Output = Self;
+
+    fn div(self, rhs: i16) -> Self::Output {
+        Self {
+            x: self.x / Fp::from(rhs),
+            y: self.y / Fp::from(rhs),
+        }
+    }
+}
+
+impl Mul<Vector> for i16 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        Vector {
+            x: self * rhs.x,
+            y: self * rhs.y,
+        }
+    }
+}
+
+impl Mul<i16> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: i16) -> Self::Output {
+        Self {
+            x: self.x * Fp::from(rhs),
+            y: self.y * Fp::from(rhs),
+        }
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Sum for Vector {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Vector {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |sum, vector| sum + *vector)
+    }
+}
+
+impl Add<(i16, i16)> for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: (i16, i16)) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
+
+impl Sub<(i16, i16)> for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: (i16, i16)) -> Self::Output {
+        self - Self::from(rhs)
+    }
+}
+
+impl Mul<(i16, i16)> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: (i16, i16)) -> Self::Output {
+        self * Self::from(rhs)
+    }
+}
+
+impl Add<(f32, f32)> for Vector {
+    type Output = Self;
 
-    fn div(self, rhs: Vector) -> Self::Output {
-        Vector {
-            x: (self / rhs.x),
-            y: (self / rhs.y),
-        }
+    fn add(self, rhs: (f32, f32)) -> Self::Output {
+        self + Self::from(rhs)
     }
 }
 
-impl Div<Fp> for Vector {
+impl Sub<(f32, f32)> for Vector {
     type Output = Self;
 
-    fn div(self, rhs: Fp) -> Self::Output {
-        Self {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        }
+    fn sub(self, rhs: (f32, f32)) -> Self::Output {
+        self - Self::from(rhs)
     }
 }
 
-impl Div<i16> for Vector {
+impl Mul<(f32, f32)> for Vector {
     type Output = Self;
 
-    fn div(self, rhs: i16) -> Self::Output {
-        Self {
-            x: self.x / Fp::from(rhs),
-            y: self.y / Fp::from(rhs),
-        }
+    fn mul(self, rhs: (f32, f32)) -> Self::Output {
+        self * Self::from(rhs)
     }
 }
 
-impl Mul<Vector> for i16 {
-    type Output = Vector;
+/// Represents a vector with integer components, for example the coordinates of a grid cell.
+#[derive(Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct IntVector {
+    pub x: i32,
+    pub y: i32,
+}
 
-    fn mul(self, rhs: Vector) -> Self::Output {
-        Vector {
-            x: self * rhs.x,
-            y: self * rhs.y,
-        }
+impl IntVector {
+    /// Creates a new `IntVector` with the specified `x` and `y` components.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
     }
 }
 
-impl Mul<i16> for Vector {
-    type Output = Self;
+impl fmt::Debug for IntVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ivec:{},{}", self.x, self.y)
+    }
+}
 
-    fn mul(self, rhs: i16) -> Self::Output {
-        Self {
-            x: self.x * Fp::from(rhs),
-            y: self.y * Fp::from(rhs),
-        }
+impl fmt::Display for IntVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ivec:{},{}", self.x, self.y)
     }
 }
 
-impl Neg for Vector {
-    type Output = Self;
+/// Easing curves used to shape the interpolation parameter, see [`Vector::ease_lerp`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Easing {
+    /// No shaping, `t` is used as is.
+    Linear,
+    /// Starts slow and accelerates, `t^2`.
+    QuadIn,
+    /// Starts fast and decelerates, `1 - (1 - t)^2`.
+    QuadOut,
+    /// Accelerates during the first half and decelerates during the second half.
+    QuadInOut,
+    /// Starts slow and accelerates, `t^3`.
+    CubicIn,
+    /// Starts fast and decelerates, `1 - (1 - t)^3`.
+    CubicOut,
+    /// Accelerates during the first half and decelerates during the second half.
+    CubicInOut,
+}
 
-    fn neg(self) -> Self {
-        Self {
-            x: -self.x,
-            y: -self.y,
+impl Easing {
+    /// Applies the easing curve to `t`, which is expected to be in the range `0..=1`.
+    #[must_use]
+    pub fn apply(self, t: Fp) -> Fp {
+        let one = Fp::one();
+        let half = Fp::from(0.5);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => one - (one - t) * (one - t),
+            Self::QuadInOut => {
+                if t < half {
+                    Fp::from(2) * t * t
+                } else {
+                    one - Fp::from(2) * (one - t) * (one - t)
+                }
+            }
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => one - (one - t) * (one - t) * (one - t),
+            Self::CubicInOut => {
+                if t < half {
+                    Fp::from(4) * t * t * t
+                } else {
+                    one - Fp::from(4) * (one - t) * (one - t) * (one - t)
+                }
+            }
         }
     }
 }
 
+/// One of the four edges of a [`Rect`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Where a point lies relative to a [`Rect`], as returned by [`Rect::classify_point`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PointClass {
+    Inside,
+    OnEdge(Edge),
+    OnCorner,
+    Outside,
+}
+
 /// Represents a rectangle in a 2D space.
 ///
 /// The `Rect` struct is defined by its position (`pos`) and size (`size`), both of which are
@@ -476,6 +1778,70 @@ impl Rect {
         Self { pos, size }
     }
 
+    /// Creates a new `Rect` of the given `size` centered on `center`.
+    #[must_use]
+    pub fn from_center(center: Vector, size: Vector) -> Self {
+        Self {
+            pos: center - size / 2,
+            size,
+        }
+    }
+
+    /// Creates a new `Rect` centered on `center` with the given `height` and an `aspect` ratio
+    /// of width divided by height, so the width is `height * aspect`.
+    #[must_use]
+    pub fn from_center_size_aspect(center: Vector, height: Fp, aspect: Fp) -> Self {
+        Self::from_center(center, Vector::new(height * aspect, height))
+    }
+
+    /// Creates a new `Rect` spanning from the bottom-left corner `min` to the top-right corner
+    /// `max`. The size is `max - min`, so it is negative if `min` is not below and left of
+    /// `max`; use [`Self::from_corners`] when the order of the corners is not known.
+    #[must_use]
+    pub fn from_min_max(min: Vector, max: Vector) -> Self {
+        Self {
+            pos: min,
+            size: max - min,
+        }
+    }
+
+    /// Creates a new `Rect` spanning between two opposite corners given in any order.
+    ///
+    /// The position is the component-wise minimum of the corners and the size is their
+    /// absolute difference, so the size is never negative.
+    #[must_use]
+    pub fn from_corners(a: Vector, b: Vector) -> Self {
+        Self {
+            pos: a.min(&b),
+            size: (a - b).abs(),
+        }
+    }
+
+    /// Returns the smallest rectangle that contains all `points`, or `None` if `points` is
+    /// empty. A single point gives a zero-size rectangle at that point.
+    #[must_use]
+    pub fn from_points(points: &[Vector]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let (min, max) = rest.iter().fold((*first, *first), |(min, max), point| {
+            (min.min(point), max.max(point))
+        });
+        Some(Self::from_min_max(min, max))
+    }
+
+    /// Creates a new `Rect`, returning `None` if either component of `size` is negative.
+    ///
+    /// Use this instead of [`Self::new`] when the rectangle comes from untrusted input, since
+    /// methods such as [`Self::right`], [`Self::area`] and [`Self::contains_point`] assume a
+    /// non-negative size.
+    #[must_use]
+    pub fn try_new(pos: Vector, size: Vector) -> Option<Self> {
+        if size.x < Fp::zero() || size.y < Fp::zero() {
+            None
+        } else {
+            Some(Self { pos, size })
+        }
+    }
+
     #[must_use]
     #[inline(always)]
     pub fn top(self) -> Fp {
@@ -496,94 +1862,611 @@ impl Rect {
 
     #[inline(always)]
     #[must_use]
-    pub fn right(self) -> Fp {
-        self.pos.x + self.size.x
+    pub fn right(self) -> Fp {
+        self.pos.x + self.size.x
+    }
+
+    /// Returns the center point of the rectangle, `pos + size / 2`.
+    #[must_use]
+    pub fn center(&self) -> Vector {
+        self.pos + self.size / 2
+    }
+
+    /// Returns a rectangle of the same size, moved so that its center is at `center`.
+    #[must_use]
+    pub fn with_center(&self, center: Vector) -> Self {
+        Self::from_center(center, self.size)
+    }
+
+    /// Returns a new `Rect` with its position translated by the given vector.
+    ///
+    /// This method is useful for moving the rectangle while keeping its size unchanged.
+    ///
+    /// # Parameters
+    /// - `offset`: A vector indicating how much to translate the rectangle's position.
+    ///
+    /// # Returns
+    /// A new `Rect` with the position translated by the given vector. The size remains unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32::Fp;
+    /// use fixed32_math::{Rect, Vector};
+    ///
+    /// let pos = Vector::new(Fp::from(1), Fp::from(2));
+    /// let size = Vector::new(Fp::from(3), Fp::from(4));
+    /// let rect = Rect::new(pos, size);
+    /// let offset = Vector::new(Fp::from(1), Fp::from(-1));
+    /// let translated_rect = rect.move_by(offset);
+    /// assert_eq!(translated_rect.pos.x, Fp::from(2));
+    /// assert_eq!(translated_rect.pos.y, Fp::from(1));
+    /// assert_eq!(translated_rect.size, size);
+    /// ```
+    #[must_use]
+    pub fn move_by(self, offset: Vector) -> Self {
+        Self {
+            pos: self.pos + offset,
+            size: self.size,
+        }
+    }
+
+    /// Converts a point from world space into the local space of the rectangle, where the
+    /// origin is at `pos`.
+    #[must_use]
+    pub fn to_local(&self, point: &Vector) -> Vector {
+        *point - self.pos
+    }
+
+    /// Converts a point from the local space of the rectangle back into world space. This is the
+    /// inverse of [`Self::to_local`].
+    #[must_use]
+    pub fn to_world(&self, point: &Vector) -> Vector {
+        *point + self.pos
+    }
+
+    /// Returns the width and height of the rectangle as a [`Size`].
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> Size {
+        Size {
+            width: self.size.x,
+            height: self.size.y,
+        }
+    }
+
+    /// Calculates the area of the rectangle.
+    #[must_use]
+    pub fn area(&self) -> Fp {
+        self.size.x * self.size.y
+    }
+
+    /// Calculates the area of the rectangle in whole units, rounded towards negative infinity.
+    ///
+    /// The multiplication is done in 64 bits, so unlike [`Self::area`] it does not overflow
+    /// for large rectangles.
+    #[must_use]
+    pub fn area_wide(&self) -> i64 {
+        self.raw_area() >> (2 * FRACTIONAL_BITS)
+    }
+
+    /// Calculates the area of the rectangle, returning `None` if it does not fit in an [`Fp`].
+    #[must_use]
+    pub fn checked_area(&self) -> Option<Fp> {
+        i32::try_from(self.raw_area() >> FRACTIONAL_BITS)
+            .ok()
+            .map(Fp::from_raw)
+    }
+
+    /// Returns the exact product of the raw width and height, which has twice the fractional
+    /// bits of an [`Fp`].
+    fn raw_area(&self) -> i64 {
+        i64::from(self.size.x.inner()) * i64::from(self.size.y.inner())
+    }
+
+    /// Returns the diagonal of the rectangle, from the bottom-left to the top-right corner.
+    #[inline]
+    #[must_use]
+    pub const fn diagonal(&self) -> Vector {
+        self.size
+    }
+
+    /// Calculates the length of the diagonal of the rectangle.
+    #[must_use]
+    pub fn diagonal_len(&self) -> Fp {
+        self.size.len()
+    }
+
+    /// Calculates the perimeter of the rectangle.
+    #[must_use]
+    pub fn perimeter(&self) -> Fp {
+        2 * (self.size.x + self.size.y)
+    }
+
+    /// Calculates the intersection of two rectangles.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let x_overlap = Fp::max(self.pos.x, other.pos.x)
+            ..Fp::min(self.pos.x + self.size.x, other.pos.x + other.size.x);
+        let y_overlap = Fp::max(self.pos.y, other.pos.y)
+            ..Fp::min(self.pos.y + self.size.y, other.pos.y + other.size.y);
+
+        if x_overlap.is_empty() || y_overlap.is_empty() {
+            None
+        } else {
+            Some(Self {
+                pos: Vector {
+                    x: x_overlap.start,
+                    y: y_overlap.start,
+                },
+                size: Vector {
+                    x: x_overlap.end - x_overlap.start,
+                    y: y_overlap.end - y_overlap.start,
+                },
+            })
+        }
+    }
+
+    /// Calculates the intersection of two rectangles together with their intersection over
+    /// union (the area of the intersection divided by the area covered by either rectangle).
+    ///
+    /// The areas are computed exactly in 64-bit integers, like [`Self::area_wide`] but without
+    /// dropping the fractional part, so neither large rectangles, which would overflow
+    /// [`Self::area`], nor tiny ones, whose area would round to zero, cause problems.
+    ///
+    /// Returns `None` if the rectangles do not overlap, or if the union has no area, which can
+    /// only happen for rectangles with a negative size.
+    #[must_use]
+    pub fn intersection_with_iou(&self, other: &Self) -> Option<(Self, Fp)> {
+        let intersection = self.intersection(other)?;
+        let intersection_area = i128::from(intersection.raw_area());
+        let union_area =
+            i128::from(self.raw_area()) + i128::from(other.raw_area()) - intersection_area;
+        if union_area <= 0 {
+            return None;
+        }
+        let iou = (intersection_area << FRACTIONAL_BITS) / union_area;
+        Some((intersection, Fp::from_raw(i32::try_from(iou).ok()?)))
+    }
+
+    /// Calculates the union of two rectangles.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let x_min = Fp::min(self.pos.x, other.pos.x);
+        let y_min = Fp::min(self.pos.y, other.pos.y);
+        let x_max = Fp::max(self.pos.x + self.size.x, other.pos.x + other.size.x);
+        let y_max = Fp::max(self.pos.y + self.size.y, other.pos.y + other.size.y);
+
+        Self {
+            pos: Vector { x: x_min, y: y_min },
+            size: Vector {
+                x: x_max - x_min,
+                y: y_max - y_min,
+            },
+        }
+    }
+
+    /// Coalesces rectangles that share a full edge into larger rectangles.
+    ///
+    /// Two rectangles are merged when they have the same bottom and height and touch
+    /// horizontally, or the same left and width and touch vertically. Merging repeats until no
+    /// such pair is left, so a row of cells collapses into a single strip. Rectangles that only
+    /// overlap, or share part of an edge, are kept apart, so the merged rectangles cover exactly
+    /// the same area as the input. The result does not depend on the platform, but it does
+    /// depend on the input order.
+    #[must_use]
+    pub fn merge_adjacent(rects: &[Self]) -> Vec<Self> {
+        let shares_edge = |a: &Self, b: &Self| {
+            (a.bottom() == b.bottom()
+                && a.size.y == b.size.y
+                && (a.right() == b.left() || b.right() == a.left()))
+                || (a.left() == b.left()
+                    && a.size.x == b.size.x
+                    && (a.top() == b.bottom() || b.top() == a.bottom()))
+        };
+
+        let mut merged = rects.to_vec();
+        'merging: loop {
+            for i in 0..merged.len() {
+                for j in i + 1..merged.len() {
+                    if shares_edge(&merged[i], &merged[j]) {
+                        merged[i] = merged[i].union(&merged[j]);
+                        merged.remove(j);
+                        continue 'merging;
+                    }
+                }
+            }
+            return merged;
+        }
+    }
+
+    /// Extends an optional accumulated bounding rectangle with `rect`, for use with `fold`.
+    ///
+    /// `None` acts as the empty accumulator, so unlike starting from a default rectangle, the
+    /// result is not stretched to include the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32_math::Rect;
+    ///
+    /// let rects = [Rect::from((5, 5, 2, 2)), Rect::from((8, 6, 1, 3))];
+    /// let bounds = rects.iter().fold(None, |acc, rect| Rect::union_opt(acc, *rect));
+    /// assert_eq!(bounds, Some(Rect::from((5, 5, 4, 4))));
+    /// ```
+    #[must_use]
+    pub fn union_opt(acc: Option<Self>, rect: Self) -> Option<Self> {
+        Some(acc.map_or(rect, |acc| acc.union(&rect)))
+    }
+
+    /// Calculates the bounding rectangle swept by moving this rectangle by `motion`.
+    ///
+    /// The result covers the rectangle at both its start and end positions, which makes it a
+    /// conservative broad-phase bound for a moving object. Negative motion extends the bounds
+    /// towards the left and bottom.
+    #[must_use]
+    pub fn swept_bounds(&self, motion: Vector) -> Self {
+        self.union(&self.move_by(motion))
+    }
+
+    /// Calculates the distance between the centers of two rectangles.
+    #[must_use]
+    pub fn center_distance(&self, other: &Self) -> Fp {
+        self.sqr_center_distance(other).sqrt()
+    }
+
+    /// Calculates the squared distance between the centers of two rectangles.
+    ///
+    /// Prefer this over [`Self::center_distance`] when only comparing distances, as it avoids the
+    /// square root.
+    #[must_use]
+    pub fn sqr_center_distance(&self, other: &Self) -> Fp {
+        self.center().sqr_distance(&other.center())
+    }
+
+    /// Splits the rectangle into a grid of `cols` by `rows` cells with a gap of `gutter` between
+    /// neighbouring cells.
+    ///
+    /// Gutters are only placed between cells, never along the outer border, so the total
+    /// deducted from the available space is `gutter * (cols - 1, rows - 1)`. The rest is divided
+    /// evenly among the cells, keeping every cell within this rectangle.
+    ///
+    /// Cells are returned row by row, starting at the bottom-left cell and moving right and up.
+    /// Returns an empty `Vec` if `cols` or `rows` is zero, or larger than [`i16::MAX`], which is
+    /// the largest count that can be represented as an [`Fp`].
+    #[must_use]
+    pub fn split_grid_spaced(&self, cols: u32, rows: u32, gutter: Vector) -> Vec<Self> {
+        let (Ok(cols), Ok(rows)) = (i16::try_from(cols), i16::try_from(rows)) else {
+            return Vec::new();
+        };
+        if cols <= 0 || rows <= 0 {
+            return Vec::new();
+        }
+        let col_count = Fp::from(cols);
+        let row_count = Fp::from(rows);
+        let cell_size = Vector {
+            x: (self.size.x - gutter.x * (col_count - Fp::one())) / col_count,
+            y: (self.size.y - gutter.y * (row_count - Fp::one())) / row_count,
+        };
+        let step = cell_size + gutter;
+
+        let mut cells = Vec::with_capacity(cols as usize * rows as usize);
+        for row in 0..rows {
+            for col in 0..cols {
+                let offset = Vector {
+                    x: step.x * Fp::from(col),
+                    y: step.y * Fp::from(row),
+                };
+                cells.push(Self::new(self.pos + offset, cell_size));
+            }
+        }
+        cells
+    }
+
+    /// Interpolates between two rectangles by moving the bottom-left and top-right corners
+    /// independently.
+    ///
+    /// `t` of zero returns `self` and `t` of one returns `other`. For some transitions, this
+    /// feels more natural than interpolating position and size separately.
+    #[must_use]
+    pub fn lerp_corners(&self, other: &Self, t: Fp) -> Self {
+        let min = self.pos.lerp(&other.pos, t);
+        let max = (self.pos + self.size).lerp(&(other.pos + other.size), t);
+        Self::from_min_max(min, max)
+    }
+
+    /// Returns the four rectangles that form a border of the given `thickness` just inside the
+    /// edges of this rectangle, in the order bottom, top, left, right.
+    ///
+    /// The bottom and top strips span the full width and own the corners. The left and right
+    /// strips fill the height between them, so the four strips never overlap or leave gaps.
+    /// The thickness of the bottom and top strips is limited to half the height, and that of the
+    /// left and right strips to half the width, so the strips meet in the middle instead of
+    /// overlapping when the border is too thick. In that case the left and right strips have
+    /// zero height.
+    #[must_use]
+    pub fn border(&self, thickness: Fp) -> [Self; 4] {
+        let horizontal = Fp::min(thickness, self.size.y / Fp::from(2));
+        let vertical = Fp::min(thickness, self.size.x / Fp::from(2));
+        let side_height = self.size.y - horizontal * Fp::from(2);
+        let side_bottom = self.bottom() + horizontal;
+        [
+            Self::new(self.pos, Vector::new(self.size.x, horizontal)),
+            Self::new(
+                Vector::new(self.left(), self.top() - horizontal),
+                Vector::new(self.size.x, horizontal),
+            ),
+            Self::new(
+                Vector::new(self.left(), side_bottom),
+                Vector::new(vertical, side_height),
+            ),
+            Self::new(
+                Vector::new(self.right() - vertical, side_bottom),
+                Vector::new(vertical, side_height),
+            ),
+        ]
+    }
+
+    /// Splits the rectangle into the nine regions used for scalable nine-slice panels.
+    ///
+    /// `insets` holds the fixed size of the border on the `(left, right, top, bottom)` sides.
+    /// The regions are returned row by row, starting at the bottom and moving right and up:
+    ///
+    /// | index | region       |
+    /// |-------|--------------|
+    /// | 0     | bottom-left  |
+    /// | 1     | bottom       |
+    /// | 2     | bottom-right |
+    /// | 3     | left         |
+    /// | 4     | center       |
+    /// | 5     | right        |
+    /// | 6     | top-left     |
+    /// | 7     | top          |
+    /// | 8     | top-right    |
+    ///
+    /// The corners keep the inset sizes while the edges and the center stretch to fill the rest.
+    #[must_use]
+    pub fn nine_slice(&self, insets: (Fp, Fp, Fp, Fp)) -> [Self; 9] {
+        let (left, right, top, bottom) = insets;
+        let xs = [
+            self.left(),
+            self.left() + left,
+            self.right() - right,
+            self.right(),
+        ];
+        let ys = [
+            self.bottom(),
+            self.bottom() + bottom,
+            self.top() - top,
+            self.top(),
+        ];
+        core::array::from_fn(|index| {
+            let (col, row) = (index % 3, index / 3);
+            Self {
+                pos: Vector::new(xs[col], ys[row]),
+                size: Vector::new(xs[col + 1] - xs[col], ys[row + 1] - ys[row]),
+            }
+        })
     }
 
-    /// Returns a new `Rect` with its position translated by the given vector.
+    /// Splits the rectangle into four quadrants that meet at `point`, for example to split a
+    /// quadtree node at the centroid of its contents.
     ///
-    /// This method is useful for moving the rectangle while keeping its size unchanged.
+    /// `point` is first clamped into the rectangle, so quadrants may have zero width or height
+    /// but never a negative one. The quadrants are returned bottom row first, like
+    /// [`Self::nine_slice`]:
     ///
-    /// # Parameters
-    /// - `offset`: A vector indicating how much to translate the rectangle's position.
+    /// | index | quadrant     |
+    /// |-------|--------------|
+    /// | 0     | bottom-left  |
+    /// | 1     | bottom-right |
+    /// | 2     | top-left     |
+    /// | 3     | top-right    |
+    #[must_use]
+    pub fn split_at(&self, point: Vector) -> [Self; 4] {
+        let split = self.closest_point(&point);
+        let xs = [self.left(), split.x, self.right()];
+        let ys = [self.bottom(), split.y, self.top()];
+        core::array::from_fn(|index| {
+            let (col, row) = (index % 2, index / 2);
+            Self {
+                pos: Vector::new(xs[col], ys[row]),
+                size: Vector::new(xs[col + 1] - xs[col], ys[row + 1] - ys[row]),
+            }
+        })
+    }
+
+    /// Returns the empty bars left in `container` after fitting `content` inside it, keeping
+    /// the aspect ratio of `content` and centering it.
     ///
-    /// # Returns
-    /// A new `Rect` with the position translated by the given vector. The size remains unchanged.
+    /// When `content` is relatively wider than `container`, the bars are returned as
+    /// `[bottom, top]`. When it is relatively taller, they are returned as `[left, right]`. When
+    /// the aspect ratios match, or `content` has zero width or height, no bars are returned.
+    /// Any odd remainder goes to the second bar, so the bars and the fitted content always
+    /// cover `container` exactly.
+    #[must_use]
+    pub fn letterbox_bars(content: &Self, container: &Self) -> Vec<Self> {
+        let content_width = i64::from(content.size.x.inner());
+        let content_height = i64::from(content.size.y.inner());
+        let container_width = i64::from(container.size.x.inner());
+        let container_height = i64::from(container.size.y.inner());
+        if content_width == 0 || content_height == 0 {
+            return Vec::new();
+        }
+
+        match (content_width * container_height).cmp(&(content_height * container_width)) {
+            Ordering::Equal => Vec::new(),
+            Ordering::Greater => {
+                let fitted =
+                    Fp::from_raw((container_width * content_height / content_width) as i32);
+                let bottom = (container.size.y - fitted) / Fp::from(2);
+                let top = container.size.y - fitted - bottom;
+                vec![
+                    Self::new(container.pos, Vector::new(container.size.x, bottom)),
+                    Self::new(
+                        Vector::new(container.left(), container.top() - top),
+                        Vector::new(container.size.x, top),
+                    ),
+                ]
+            }
+            Ordering::Less => {
+                let fitted =
+                    Fp::from_raw((container_height * content_width / content_height) as i32);
+                let left = (container.size.x - fitted) / Fp::from(2);
+                let right = container.size.x - fitted - left;
+                vec![
+                    Self::new(container.pos, Vector::new(left, container.size.y)),
+                    Self::new(
+                        Vector::new(container.right() - right, container.bottom()),
+                        Vector::new(right, container.size.y),
+                    ),
+                ]
+            }
+        }
+    }
+
+    /// Packs rectangles of the given `sizes` into `bin` with a simple shelf packer.
     ///
-    /// # Examples
+    /// Sizes are placed in the order given, left to right along a shelf that starts at the
+    /// bottom of the bin. A shelf is as tall as the tallest rectangle placed on it. When a
+    /// rectangle does not fit in the remaining width, a new shelf is started on top of the
+    /// current one. Rectangles that fit on neither are skipped, and later ones may still fit.
     ///
-    /// ```
-    /// use fixed32::Fp;
-    /// use fixed32_math::{Rect, Vector};
+    /// Returns the position (bottom-left corner) of each size, or `None` for sizes that did not
+    /// fit. The result only depends on the input, so it is identical on every platform.
+    #[must_use]
+    pub fn pack(bin: Self, sizes: &[Vector]) -> Vec<Option<Vector>> {
+        let mut cursor = bin.pos;
+        let mut shelf_height = Fp::zero();
+        sizes
+            .iter()
+            .map(|size| {
+                if size.x > bin.size.x || size.y > bin.size.y {
+                    return None;
+                }
+                let mut position = cursor;
+                if position.x + size.x > bin.right() {
+                    position = Vector::new(bin.left(), cursor.y + shelf_height);
+                    if position.y + size.y > bin.top() {
+                        return None;
+                    }
+                    shelf_height = Fp::zero();
+                } else if position.y + size.y > bin.top() {
+                    return None;
+                }
+                cursor = Vector::new(position.x + size.x, position.y);
+                shelf_height = Fp::max(shelf_height, size.y);
+                Some(position)
+            })
+            .collect()
+    }
+
+    /// Returns the grid cell containing `point`, for a grid of cells with size `cell` that
+    /// starts at the bottom-left corner of the rectangle.
     ///
-    /// let pos = Vector::new(Fp::from(1), Fp::from(2));
-    /// let size = Vector::new(Fp::from(3), Fp::from(4));
-    /// let rect = Rect::new(pos, size);
-    /// let offset = Vector::new(Fp::from(1), Fp::from(-1));
-    /// let translated_rect = rect.move_by(offset);
-    /// assert_eq!(translated_rect.pos.x, Fp::from(2));
-    /// assert_eq!(translated_rect.pos.y, Fp::from(1));
-    /// assert_eq!(translated_rect.size, size);
-    /// ```
+    /// Returns `None` if the point is outside the rectangle, or if either component of `cell` is
+    /// zero or negative. As with [`Self::contains_point`], points on the right and top edges are
+    /// outside, so every point maps to exactly one cell.
     #[must_use]
-    pub fn move_by(self, offset: Vector) -> Self {
-        Self {
-            pos: self.pos + offset,
-            size: self.size,
+    pub fn cell_at(&self, point: &Vector, cell: Vector) -> Option<IntVector> {
+        if cell.x <= Fp::zero() || cell.y <= Fp::zero() || !self.contains_point(point) {
+            return None;
         }
+        let local = *point - self.pos;
+        Some(IntVector::new(
+            (local.x / cell.x).inner() >> FRACTIONAL_BITS,
+            (local.y / cell.y).inner() >> FRACTIONAL_BITS,
+        ))
     }
 
-    /// Calculates the area of the rectangle.
-    #[must_use]
-    pub fn area(&self) -> Fp {
-        self.size.x * self.size.y
+    /// Returns the horizontal pixel spans covering the rectangle, as `(y, x_start, x_end)` with
+    /// `x_end` exclusive, from the bottom row up.
+    ///
+    /// A pixel is covered when its center, at `(x + 0.5, y + 0.5)`, lies inside the rectangle,
+    /// with the left and bottom edges inclusive and the right and top edges exclusive as in
+    /// [`Self::contains_point`]. With this rule, rectangles that share an edge never fill the
+    /// same pixel twice and never leave a gap between them. Rectangles that cover no pixel
+    /// centers yield no spans.
+    pub fn scanlines(&self) -> impl Iterator<Item = (i32, i32, i32)> {
+        let first_pixel = |edge: Fp| {
+            let half = 1_i64 << (FRACTIONAL_BITS - 1);
+            ((i64::from(edge.inner()) + half - 1) >> FRACTIONAL_BITS) as i32
+        };
+        let x_start = first_pixel(self.left());
+        let x_end = first_pixel(self.right());
+        let rows = if x_start < x_end {
+            first_pixel(self.bottom())..first_pixel(self.top())
+        } else {
+            0..0
+        };
+        rows.map(move |y| (y, x_start, x_end))
     }
 
-    /// Calculates the perimeter of the rectangle.
+    /// Maps two random numbers from `rng` to a uniformly distributed point inside the rectangle.
+    ///
+    /// The first number picks the x coordinate and the second the y coordinate. Each one is
+    /// treated as a fraction of `2^32` and scaled by the size using integer arithmetic only, so
+    /// the same random numbers give a bit-identical point on every platform. The point lies on
+    /// or right of the left edge and strictly left of the right edge, and likewise vertically.
     #[must_use]
-    pub fn perimeter(&self) -> Fp {
-        2 * (self.size.x + self.size.y)
+    pub fn random_point(&self, rng: &mut impl FnMut() -> u32) -> Vector {
+        let mut offset =
+            |size: Fp| Fp::from_raw(((i128::from(size.inner()) * i128::from(rng())) >> 32) as i32);
+        let x = offset(self.size.x);
+        let y = offset(self.size.y);
+        self.pos + Vector::new(x, y)
     }
 
-    /// Calculates the intersection of two rectangles.
+    /// Checks if the position and size each differ from those of `other` by at most `epsilon`
+    /// per component, as with [`Vector::approx_eq`].
     #[must_use]
-    pub fn intersection(&self, other: &Self) -> Option<Self> {
-        let x_overlap = Fp::max(self.pos.x, other.pos.x)
-            ..Fp::min(self.pos.x + self.size.x, other.pos.x + other.size.x);
-        let y_overlap = Fp::max(self.pos.y, other.pos.y)
-            ..Fp::min(self.pos.y + self.size.y, other.pos.y + other.size.y);
+    pub fn approx_eq(&self, other: &Self, epsilon: Fp) -> bool {
+        self.pos.approx_eq(&other.pos, epsilon) && self.size.approx_eq(&other.size, epsilon)
+    }
 
-        if x_overlap.is_empty() || y_overlap.is_empty() {
+    /// Classifies `point` as strictly inside the rectangle, exactly on one of its edges, exactly
+    /// on one of its corners, or outside.
+    ///
+    /// Unlike [`Self::contains_point`], all four edges are treated the same way, which is what
+    /// picking a resize handle under the cursor needs.
+    #[must_use]
+    pub fn classify_point(&self, point: &Vector) -> PointClass {
+        if point.x < self.left()
+            || point.x > self.right()
+            || point.y < self.bottom()
+            || point.y > self.top()
+        {
+            return PointClass::Outside;
+        }
+        let horizontal = if point.x == self.left() {
+            Some(Edge::Left)
+        } else if point.x == self.right() {
+            Some(Edge::Right)
+        } else {
             None
+        };
+        let vertical = if point.y == self.bottom() {
+            Some(Edge::Bottom)
+        } else if point.y == self.top() {
+            Some(Edge::Top)
         } else {
-            Some(Self {
-                pos: Vector {
-                    x: x_overlap.start,
-                    y: y_overlap.start,
-                },
-                size: Vector {
-                    x: x_overlap.end - x_overlap.start,
-                    y: y_overlap.end - y_overlap.start,
-                },
-            })
+            None
+        };
+        match (horizontal, vertical) {
+            (Some(_), Some(_)) => PointClass::OnCorner,
+            (Some(edge), None) | (None, Some(edge)) => PointClass::OnEdge(edge),
+            (None, None) => PointClass::Inside,
         }
     }
 
-    /// Calculates the union of two rectangles.
+    /// Returns the point on or inside the rectangle that is closest to `point`, by clamping
+    /// each component into the rectangle. Points inside the rectangle are returned unchanged.
+    ///
+    /// For circle-versus-rectangle tests, the circle overlaps the rectangle when the distance
+    /// from its center to this point is less than its radius.
     #[must_use]
-    pub fn union(&self, other: &Self) -> Self {
-        let x_min = Fp::min(self.pos.x, other.pos.x);
-        let y_min = Fp::min(self.pos.y, other.pos.y);
-        let x_max = Fp::max(self.pos.x + self.size.x, other.pos.x + other.size.x);
-        let y_max = Fp::max(self.pos.y + self.size.y, other.pos.y + other.size.y);
-
-        Self {
-            pos: Vector { x: x_min, y: y_min },
-            size: Vector {
-                x: x_max - x_min,
-                y: y_max - y_min,
-            },
-        }
+    pub fn closest_point(&self, point: &Vector) -> Vector {
+        point.max(&self.pos).min(&(self.pos + self.size))
     }
 
     /// Checks if a point is inside the rectangle.
@@ -595,19 +2478,72 @@ impl Rect {
             && point.y < self.pos.y + self.size.y
     }
 
+    /// Checks if a point is inside the rectangle or on its border.
+    ///
+    /// Unlike [`Self::contains_point`], the right and top edges are included as well.
+    #[must_use]
+    pub fn contains_point_inclusive(&self, point: &Vector) -> bool {
+        point.x >= self.left()
+            && point.x <= self.right()
+            && point.y >= self.bottom()
+            && point.y <= self.top()
+    }
+
+    /// Checks if the segment between `a` and `b` is completely inside the rectangle.
+    ///
+    /// Since the rectangle is convex, this only needs to check that both endpoints are inside
+    /// or on the border.
+    #[must_use]
+    pub fn contains_segment(&self, a: &Vector, b: &Vector) -> bool {
+        self.contains_point_inclusive(a) && self.contains_point_inclusive(b)
+    }
+
+    /// Checks if a point is inside the rectangle, or at most `tolerance` outside any edge.
+    ///
+    /// Unlike [`Self::contains_point`], the edges are inclusive, so a point exactly `tolerance`
+    /// away from the rectangle is still considered contained.
+    #[must_use]
+    pub fn contains_point_with_tolerance(&self, point: &Vector, tolerance: Fp) -> bool {
+        point.x >= self.left() - tolerance
+            && point.x <= self.right() + tolerance
+            && point.y >= self.bottom() - tolerance
+            && point.y <= self.top() + tolerance
+    }
+
     /// Checks if another rectangle is completely inside this rectangle.
+    ///
+    /// The edges are inclusive, so a rectangle that shares edges with this one, or exactly
+    /// fills it, is contained.
     #[must_use]
     pub fn contains_rect(&self, other: &Self) -> bool {
-        self.contains_point(&other.pos) && self.contains_point(&(other.pos + other.size))
+        other.left() >= self.left()
+            && other.right() <= self.right()
+            && other.bottom() >= self.bottom()
+            && other.top() <= self.top()
     }
 
+    /// Checks if the two rectangles share any area.
+    ///
+    /// Rectangles that only touch along an edge or at a corner do not overlap, which matches
+    /// [`Self::intersection`] returning `None` for them.
     #[inline]
     #[must_use]
     pub fn is_overlapping(self, other: Self) -> bool {
-        !(self.right() < other.left()
-            || self.left() > other.right()
-            || self.bottom() > other.top()
-            || self.top() < other.bottom())
+        self.left() < other.right()
+            && self.right() > other.left()
+            && self.bottom() < other.top()
+            && self.top() > other.bottom()
+    }
+
+    /// Checks if any part of the rectangle is visible in `viewport`, for culling.
+    ///
+    /// This is [`Self::is_overlapping`] under a name that reads well at the call site, so the
+    /// edges are exclusive: a rectangle that only touches the border of the viewport from the
+    /// outside covers no visible area and is culled. A rectangle with zero size is visible if it
+    /// lies strictly inside the viewport.
+    #[must_use]
+    pub fn is_visible_in(&self, viewport: &Self) -> bool {
+        self.is_overlapping(*viewport)
     }
 
     /// Expands the rectangle by a given offset.
@@ -628,10 +2564,248 @@ impl Rect {
         }
     }
 
-    /// Calculates the aspect ratio of the rectangle.
+    /// Moves each edge inward by its own amount, for asymmetric padding. Negative amounts move
+    /// the edge outward instead.
+    ///
+    /// The size is clamped at zero, so an inset larger than the rectangle collapses it to zero
+    /// width or height at the moved left or bottom edge instead of giving a negative size.
+    #[must_use]
+    pub fn inset(&self, left: Fp, right: Fp, top: Fp, bottom: Fp) -> Self {
+        Self {
+            pos: Vector::new(self.pos.x + left, self.pos.y + bottom),
+            size: Vector::new(
+                Fp::max(self.size.x - left - right, Fp::zero()),
+                Fp::max(self.size.y - top - bottom, Fp::zero()),
+            ),
+        }
+    }
+
+    /// Clamps the size of the rectangle between `min` and `max`, keeping its position.
+    ///
+    /// Since the position is kept, the bottom-left corner stays anchored while the right and top
+    /// edges move.
+    #[must_use]
+    pub fn clamp_size(&self, min: Vector, max: Vector) -> Self {
+        Self {
+            pos: self.pos,
+            size: Vector {
+                x: Fp::min(Fp::max(self.size.x, min.x), max.x),
+                y: Fp::min(Fp::max(self.size.y, min.y), max.y),
+            },
+        }
+    }
+
+    /// Returns the corners of the rectangle as a closed line strip for drawing its outline.
+    ///
+    /// The corners are in the same counter-clockwise order as [`Self::corners`], starting at the
+    /// bottom-left, followed by the bottom-left corner again to close the loop.
+    #[must_use]
+    pub fn outline_strip(&self) -> [Vector; 5] {
+        let [bottom_left, bottom_right, top_right, top_left] = self.corners();
+        [bottom_left, bottom_right, top_right, top_left, bottom_left]
+    }
+
+    /// Casts a ray against the rectangle using the slab method.
+    ///
+    /// Returns the parameter `t` at which the ray enters the rectangle, so the hit point is
+    /// [`Ray::point_at`]`(t)`, together with the outward normal of the face that was hit. The
+    /// normal is one of [`Vector::left`], [`Vector::right`], [`Vector::down`] or [`Vector::up`],
+    /// chosen by the slab that produced the entry `t`. If the ray enters through a corner, the
+    /// vertical face (and thus a horizontal normal) is reported.
+    ///
+    /// Returns `None` if the ray misses, if the rectangle is behind the ray, or if the ray starts
+    /// inside the rectangle, since then there is no entry face.
+    #[must_use]
+    pub fn raycast(&self, ray: &Ray) -> Option<(Fp, Vector)> {
+        let (enter_x, exit_x, normal_x) = slab(
+            ray.origin.x,
+            ray.direction.x,
+            self.left(),
+            self.right(),
+            Vector::left(),
+        )?;
+        let (enter_y, exit_y, normal_y) = slab(
+            ray.origin.y,
+            ray.direction.y,
+            self.bottom(),
+            self.top(),
+            Vector::down(),
+        )?;
+
+        let (enter, normal) = match (enter_x, enter_y) {
+            (Some(x), Some(y)) if y > x => (y, normal_y),
+            (Some(x), _) => (x, normal_x),
+            (None, Some(y)) => (y, normal_y),
+            (None, None) => return None,
+        };
+        let exit = match (exit_x, exit_y) {
+            (Some(x), Some(y)) => Fp::min(x, y),
+            (Some(x), None) => x,
+            (None, Some(y)) => y,
+            (None, None) => return None,
+        };
+
+        if enter < Fp::zero() || enter > exit {
+            None
+        } else {
+            Some((enter, normal))
+        }
+    }
+
+    /// Returns the rectangle with both position and size rounded down to whole units.
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        Self {
+            pos: self.pos.floor(),
+            size: self.size.floor(),
+        }
+    }
+
+    /// Returns the rectangle with both position and size rounded up to whole units.
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        Self {
+            pos: self.pos.ceil(),
+            size: self.size.ceil(),
+        }
+    }
+
+    /// Returns the rectangle with both position and size rounded to the nearest whole units.
+    ///
+    /// Position and size are rounded independently, so the right and top edges can move by up
+    /// to a whole unit. Use [`Self::round_edges`] to keep every edge at its nearest whole unit.
+    #[must_use]
+    pub fn round(&self) -> Self {
+        Self {
+            pos: self.pos.round(),
+            size: self.size.round(),
+        }
+    }
+
+    /// Returns the rectangle with each of its four edges rounded to the nearest whole unit.
+    ///
+    /// The size is derived from the rounded edges, so every edge moves by at most half a unit
+    /// and rectangles that share an edge keep sharing it after snapping.
+    #[must_use]
+    pub fn round_edges(&self) -> Self {
+        let left = self.left().round();
+        let bottom = self.bottom().round();
+        Self {
+            pos: Vector::new(left, bottom),
+            size: Vector::new(self.right().round() - left, self.top().round() - bottom),
+        }
+    }
+
+    /// Scales the whole rectangle uniformly by `factor` about the given `point`.
+    ///
+    /// Both the offset of the position from `point` and the size are scaled, so `point` keeps
+    /// its relative place within (or outside) the rectangle. This is what zooming towards a
+    /// cursor position needs.
+    #[must_use]
+    pub fn scale_about(&self, factor: Fp, point: Vector) -> Self {
+        Self {
+            pos: point + (self.pos - point) * factor,
+            size: self.size * factor,
+        }
+    }
+
+    /// Moves a single `edge` of the rectangle by `delta`, keeping the opposite edge in place.
+    ///
+    /// A positive `delta` moves the edge right or up, a negative one left or down. Moving the
+    /// left or bottom edge therefore changes both the position and the size.
+    #[must_use]
+    pub fn resize_edge(&self, edge: Edge, delta: Fp) -> Self {
+        let (pos, size) = match edge {
+            Edge::Left => (
+                Vector::new(self.pos.x + delta, self.pos.y),
+                Vector::new(self.size.x - delta, self.size.y),
+            ),
+            Edge::Right => (self.pos, Vector::new(self.size.x + delta, self.size.y)),
+            Edge::Bottom => (
+                Vector::new(self.pos.x, self.pos.y + delta),
+                Vector::new(self.size.x, self.size.y - delta),
+            ),
+            Edge::Top => (self.pos, Vector::new(self.size.x, self.size.y + delta)),
+        };
+        Self { pos, size }
+    }
+
+    /// Zooms a view rectangle by `factor` while keeping the world point `focus` at the same
+    /// position on screen, as when zooming towards the cursor.
+    ///
+    /// A `factor` above one zooms in, so the view covers a smaller area, and a `factor` below
+    /// one zooms out. `factor` must be positive.
+    #[must_use]
+    pub fn zoom_at(&self, factor: Fp, focus: Vector) -> Self {
+        Self {
+            pos: focus - (focus - self.pos) / factor,
+            size: self.size / factor,
+        }
+    }
+
+    /// Returns a rectangle covering the same region with a non-negative size.
+    ///
+    /// A negative size component, for example from subtracting positions, moves `pos` to the
+    /// other side and flips the sign of that component. Methods such as
+    /// [`Self::contains_point`], [`Self::area`] and [`Self::intersection`] assume a
+    /// non-negative size.
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        Self::from_corners(self.pos, self.pos + self.size)
+    }
+
+    /// Calculates the aspect ratio of the rectangle, its width divided by its height.
+    /// Returns `None` if the height is zero.
+    #[must_use]
+    pub fn aspect_ratio(&self) -> Option<Fp> {
+        if self.size.y.is_zero() {
+            None
+        } else {
+            Some(self.size.x / self.size.y)
+        }
+    }
+
+    /// Returns the four corners of the rectangle in counter-clockwise order, starting at the
+    /// bottom-left: bottom-left, bottom-right, top-right, top-left.
+    #[must_use]
+    pub fn corners(&self) -> [Vector; 4] {
+        [
+            Vector::new(self.left(), self.bottom()),
+            Vector::new(self.right(), self.bottom()),
+            Vector::new(self.right(), self.top()),
+            Vector::new(self.left(), self.top()),
+        ]
+    }
+
+    /// Applies `f` to each corner of the rectangle and returns the results.
+    ///
+    /// The corners are visited in the counter-clockwise order of [`Self::corners`]. The transform
+    /// does not need to be affine, so the returned points are not guaranteed to form a rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed32_math::{Rect, Vector};
+    ///
+    /// let rect = Rect::from((0, 0, 2, 1));
+    /// let moved = rect.map_corners(|corner| corner + Vector::from((1, 1)));
+    /// assert_eq!(moved[0], Vector::from((1, 1)));
+    /// assert_eq!(moved[2], Vector::from((3, 2)));
+    /// ```
+    #[must_use]
+    pub fn map_corners<F: Fn(Vector) -> Vector>(&self, f: F) -> [Vector; 4] {
+        self.corners().map(f)
+    }
+
+    /// Returns the four corners of the rectangle rotated by `angle` radians about its center,
+    /// in the same order as [`Self::corners`].
+    ///
+    /// This gives the vertices of a quad for drawing the rectangle rotated in place.
     #[must_use]
-    pub fn aspect_ratio(&self) -> Fp {
-        self.size.x / self.size.y
+    pub fn rotated_corners(&self, angle: Fp) -> [Vector; 4] {
+        let center = self.center();
+        let rotate = Vector::rotator(angle);
+        self.map_corners(|corner| center + rotate(corner - center))
     }
 }
 
@@ -672,3 +2846,113 @@ impl From<(f32, f32, f32, f32)> for Rect {
         }
     }
 }
+
+/// Represents the width and height of something in a 2D space.
+///
+/// `Size` is a distinct type from [`Vector`], so that positions and sizes can not be mixed up
+/// by accident. It converts to and from [`Vector`] for interoperability with the rest of the API.
+///
+/// # Examples
+///
+/// ```
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Size, Vector};
+///
+/// let rect = Rect::from((1, 2, 3, 4));
+/// let size = rect.size();
+/// assert_eq!(size.width, Fp::from(3));
+/// assert_eq!(size.height, Fp::from(4));
+/// assert_eq!(Vector::from(size), rect.size);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub struct Size {
+    pub width: Fp,
+    pub height: Fp,
+}
+
+impl Size {
+    /// Creates a new `Size` with the specified `width` and `height`.
+    #[inline]
+    #[must_use]
+    pub const fn new(width: Fp, height: Fp) -> Self {
+        Self { width, height }
+    }
+}
+
+impl fmt::Debug for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "size:{},{}", self.width, self.height)
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "size:{},{}", self.width, self.height)
+    }
+}
+
+impl From<Vector> for Size {
+    fn from(vector: Vector) -> Self {
+        Self {
+            width: vector.x,
+            height: vector.y,
+        }
+    }
+}
+
+impl From<Size> for Vector {
+    fn from(size: Size) -> Self {
+        Self {
+            x: size.width,
+            y: size.height,
+        }
+    }
+}
+
+/// Represents a ray in a 2D space, starting at `origin` and extending along `direction`.
+///
+/// The direction does not need to have unit length. Distances along the ray are measured in
+/// multiples of the direction, so the point at `t` is `origin + direction * t`.
+///
+/// # Examples
+///
+/// ```
+/// use fixed32::Fp;
+/// use fixed32_math::{Ray, Rect, Vector};
+///
+/// let ray = Ray::new(Vector::from((-5, 2)), Vector::right());
+/// let rect = Rect::from((0, 0, 4, 4));
+/// assert_eq!(rect.raycast(&ray), Some((Fp::from(5), Vector::left())));
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub struct Ray {
+    pub origin: Vector,
+    pub direction: Vector,
+}
+
+impl Ray {
+    /// Creates a new `Ray` starting at `origin` and extending along `direction`.
+    #[inline]
+    #[must_use]
+    pub const fn new(origin: Vector, direction: Vector) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at `t` along the ray, which is `origin + direction * t`.
+    #[must_use]
+    pub fn point_at(&self, t: Fp) -> Vector {
+        self.origin + self.direction * t
+    }
+}
+
+impl fmt::Debug for Ray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ray:({:?},{:?})", self.origin, self.direction)
+    }
+}
+
+impl fmt::Display for Ray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.origin, self.direction)
+    }
+}
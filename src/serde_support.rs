@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/fixed32-math-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Serde support for [`Vector`] and [`Rect`].
+//!
+//! Human-readable formats (such as JSON) get named structs with the components written as
+//! decimal numbers, e.g. `{"x":1.5,"y":-2.0}`. Compact formats (such as bincode) get tuples of
+//! the raw fixed-point values. Both representations round-trip losslessly.
+
+use fixed32::Fp;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Rect, Vector, FRACTIONAL_BITS};
+
+const SCALE: f64 = (1u64 << FRACTIONAL_BITS) as f64;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vector")]
+struct ReadableVector {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Rect")]
+struct ReadableRect {
+    pos: Vector,
+    size: Vector,
+}
+
+fn fp_to_f64(value: Fp) -> f64 {
+    f64::from(value.inner()) / SCALE
+}
+
+/// Converts a decimal number to the nearest [`Fp`], or `None` if it is out of range.
+fn fp_from_f64(value: f64) -> Option<Fp> {
+    let scaled = value * SCALE;
+    let rounded = if scaled >= 0.0 {
+        scaled + 0.5
+    } else {
+        scaled - 0.5
+    };
+    // The cast truncates towards zero, so anything strictly between one below `i32::MIN` and
+    // one above `i32::MAX` lands in range.
+    (rounded > f64::from(i32::MIN) - 1.0 && rounded < f64::from(i32::MAX) + 1.0)
+        .then(|| Fp::from_raw(rounded as i32))
+}
+
+impl Serialize for Vector {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            ReadableVector {
+                x: fp_to_f64(self.x),
+                y: fp_to_f64(self.y),
+            }
+            .serialize(serializer)
+        } else {
+            (self.x.inner(), self.y.inner()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let readable = ReadableVector::deserialize(deserializer)?;
+            let convert = |value| {
+                fp_from_f64(value).ok_or_else(|| D::Error::custom("value out of range for Fp"))
+            };
+            Ok(Self {
+                x: convert(readable.x)?,
+                y: convert(readable.y)?,
+            })
+        } else {
+            let (x, y) = <(i32, i32)>::deserialize(deserializer)?;
+            Ok(Self {
+                x: Fp::from_raw(x),
+                y: Fp::from_raw(y),
+            })
+        }
+    }
+}
+
+impl Serialize for Rect {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            ReadableRect {
+                pos: self.pos,
+                size: self.size,
+            }
+            .serialize(serializer)
+        } else {
+            (self.pos, self.size).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Rect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let readable = ReadableRect::deserialize(deserializer)?;
+            Ok(Self {
+                pos: readable.pos,
+                size: readable.size,
+            })
+        } else {
+            let (pos, size) = <(Vector, Vector)>::deserialize(deserializer)?;
+            Ok(Self { pos, size })
+        }
+    }
+}
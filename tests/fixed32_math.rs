@@ -4,7 +4,7 @@
  */
 use fixed32::Fp;
 
-use fixed32_math::{Rect, Vector};
+use fixed32_math::{Easing, Edge, IntVector, PointClass, Ray, Rect, Size, Vector};
 
 #[test]
 fn multiply_fp_vector() {
@@ -71,7 +71,7 @@ fn test_rotate_90_degrees() {
 #[test]
 fn test_rotate_270_degrees() {
     let vector = Vector::new(Fp::from(1.0), Fp::from(0.0));
-    let angle = Fp::from(3.0 * std::f32::consts::FRAC_PI_2); // 270 degrees (3π/2)
+    let angle = Fp::from(3.0 * core::f32::consts::FRAC_PI_2); // 270 degrees (3π/2)
     let rotated = vector.rotate(angle);
     let expected_vector = Vector::new(Fp::from(0.0), Fp::from(-1.0));
     let len = (expected_vector - rotated).sqr_len();
@@ -120,3 +120,1783 @@ fn test_intersection_full_overlap() {
     let expected = Rect::new(Vector::from((2, 2)), Vector::from((5, 5)));
     assert_eq!(rect1.intersection(&rect2), Some(expected));
 }
+
+#[test]
+fn test_map_corners() {
+    let rect = Rect::from((1, 2, 3, 4));
+    let mapped = rect.map_corners(|corner| Vector::new(corner.y, corner.x));
+    assert_eq!(
+        mapped,
+        [
+            Vector::from((2, 1)),
+            Vector::from((2, 4)),
+            Vector::from((6, 4)),
+            Vector::from((6, 1)),
+        ]
+    );
+}
+
+#[test]
+fn test_normalize_l1() {
+    let normalized = Vector::from((3, -1)).normalize_l1().unwrap();
+    assert_eq!(normalized, Vector::from((0.75, -0.25)));
+    assert_eq!(normalized.x.abs() + normalized.y.abs(), Fp::one());
+    assert_eq!(Vector::default().normalize_l1(), None);
+}
+
+#[test]
+fn test_contains_point_with_tolerance() {
+    let rect = Rect::new(Vector::from((0, 0)), Vector::from((10, 10)));
+    let tolerance = Fp::from(2);
+    assert!(rect.contains_point_with_tolerance(&Vector::from((5, 5)), tolerance));
+    assert!(rect.contains_point_with_tolerance(&Vector::from((12, 5)), tolerance));
+    assert!(rect.contains_point_with_tolerance(&Vector::from((-2, -2)), tolerance));
+    assert!(!rect.contains_point_with_tolerance(&Vector::from((5, 13)), tolerance));
+}
+
+#[test]
+fn test_reflect_off_segment() {
+    let wall_a = Vector::from((0, 0));
+    let wall_b = Vector::from((10, 0));
+    let velocity = Vector::from((3, -2));
+    let reflected = Vector::reflect_off_segment(&velocity, &wall_a, &wall_b);
+    assert_eq!(reflected, Vector::from((3, 2)));
+
+    let diagonal =
+        Vector::reflect_off_segment(&Vector::from((1, 0)), &wall_a, &Vector::from((4, 4)));
+    assert_eq!(diagonal, Vector::from((0, 1)));
+}
+
+#[test]
+fn test_reflect_off_long_segment() {
+    let wall_a = Vector::from((-50, 0));
+    let wall_b = Vector::from((150, 0));
+    let velocity = Vector::from((30, -40));
+    assert_eq!(
+        Vector::reflect_off_segment(&velocity, &wall_a, &wall_b),
+        Vector::from((30, 40))
+    );
+
+    let diagonal = Vector::reflect_off_segment(
+        &Vector::from((20, 0)),
+        &Vector::from((0, 0)),
+        &Vector::from((100, 100)),
+    );
+    assert_eq!(diagonal, Vector::from((0, 20)));
+}
+
+#[test]
+fn test_reflect_off_zero_length_segment() {
+    let point = Vector::from((2, 2));
+    let velocity = Vector::from((3, -2));
+    assert_eq!(
+        Vector::reflect_off_segment(&velocity, &point, &point),
+        velocity
+    );
+}
+
+#[test]
+fn test_swept_bounds() {
+    let rect = Rect::from((0, 0, 2, 2));
+    assert_eq!(
+        rect.swept_bounds(Vector::from((5, 1))),
+        Rect::from((0, 0, 7, 3))
+    );
+    assert_eq!(
+        rect.swept_bounds(Vector::from((-3, -4))),
+        Rect::from((-3, -4, 5, 6))
+    );
+}
+
+#[test]
+fn test_total_cmp() {
+    let mut points = [
+        Vector::from((2, 1)),
+        Vector::from((-1, 5)),
+        Vector::from((2, -3)),
+        Vector::from((0, 0)),
+    ];
+    points.sort_by(Vector::total_cmp);
+    assert_eq!(
+        points,
+        [
+            Vector::from((-1, 5)),
+            Vector::from((0, 0)),
+            Vector::from((2, -3)),
+            Vector::from((2, 1)),
+        ]
+    );
+    assert_eq!(
+        Vector::from((1, 1)).total_cmp(&Vector::from((1, 1))),
+        core::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn test_distance_to_segment_squared() {
+    let a = Vector::from((0, 0));
+    let b = Vector::from((10, 0));
+    assert_eq!(
+        Vector::from((5, 3)).distance_to_segment_squared(&a, &b),
+        Fp::from(9)
+    );
+    assert_eq!(
+        Vector::from((-3, 4)).distance_to_segment_squared(&a, &b),
+        Fp::from(25)
+    );
+    assert_eq!(
+        Vector::from((13, -4)).distance_to_segment_squared(&a, &b),
+        Fp::from(25)
+    );
+    assert_eq!(
+        Vector::from((3, 4)).distance_to_segment_squared(&a, &a),
+        Fp::from(25)
+    );
+}
+
+#[test]
+fn test_distance_to_long_segment_squared() {
+    let a = Vector::from((0, 0));
+    let b = Vector::from((100, 0));
+    assert_eq!(
+        Vector::from((50, 1)).distance_to_segment_squared(&a, &b),
+        Fp::one()
+    );
+    assert_eq!(
+        Vector::from((120, 100))
+            .distance_to_segment_squared(&Vector::from((0, 100)), &Vector::from((200, 100))),
+        Fp::zero()
+    );
+    assert_eq!(
+        Vector::from((105, 0)).distance_to_segment_squared(&a, &b),
+        Fp::from(25)
+    );
+}
+
+#[test]
+fn test_center_distance() {
+    let rect1 = Rect::from((0, 0, 2, 2));
+    let rect2 = Rect::from((3, 4, 2, 2));
+    assert_eq!(rect1.sqr_center_distance(&rect2), Fp::from(25));
+    assert_eq!(rect1.center_distance(&rect2), Fp::from(5));
+    assert_eq!(rect1.center_distance(&rect1), Fp::zero());
+}
+
+#[test]
+fn test_snap_to_nearest_edge() {
+    let rect = Rect::from((0, 0, 10, 6));
+    assert_eq!(
+        Vector::from((8, 3)).snap_to_nearest_edge(&rect),
+        Vector::from((10, 3))
+    );
+    assert_eq!(
+        Vector::from((4, 1)).snap_to_nearest_edge(&rect),
+        Vector::from((4, 0))
+    );
+    assert_eq!(
+        Vector::from((3, 3)).snap_to_nearest_edge(&rect),
+        Vector::from((0, 3))
+    );
+    assert_eq!(
+        Vector::from((1, 1)).snap_to_nearest_edge(&rect),
+        Vector::from((0, 1))
+    );
+    assert_eq!(
+        Vector::from((12, 8)).snap_to_nearest_edge(&rect),
+        Vector::from((10, 6))
+    );
+}
+
+#[test]
+fn test_area_wide() {
+    let rect = Rect::from((0, 0, 3, 4));
+    assert_eq!(rect.area_wide(), 12);
+    assert_eq!(rect.checked_area(), Some(Fp::from(12)));
+
+    let large = Rect::from((0, 0, 20000, 30000));
+    assert_eq!(large.area_wide(), 600_000_000);
+    assert_eq!(large.checked_area(), None);
+}
+
+#[test]
+fn test_grid_index_round_trip() {
+    let cell = Vector::from((2, 3));
+    for index in 0..12 {
+        let position = Vector::from_index(index, 4, cell).unwrap();
+        assert_eq!(position.to_index(4, cell), Some(index));
+    }
+    assert_eq!(Vector::from_index(5, 4, cell), Some(Vector::from((2, 3))));
+    assert_eq!(Vector::from((3.5, 4.0)).to_index(4, cell), Some(5));
+    assert_eq!(Vector::from((-1, 0)).to_index(4, cell), None);
+    assert_eq!(Vector::from((8, 0)).to_index(4, cell), None);
+}
+
+#[test]
+fn test_grid_index_out_of_range() {
+    let unit = Vector::from((1, 1));
+    assert_eq!(Vector::from_index(40000, 1, unit), None);
+    assert_eq!(Vector::from_index(40000, 50000, unit), None);
+    assert_eq!(Vector::from_index(3, 0, unit), None);
+    assert_eq!(Vector::from((1, 1)).to_index(4, Vector::from((0, 1))), None);
+    assert_eq!(Vector::from((1, 1)).to_index(4, Vector::from((1, 0))), None);
+}
+
+#[test]
+fn test_split_grid_spaced() {
+    let rect = Rect::from((0, 0, 32, 10));
+    let cells = rect.split_grid_spaced(3, 2, Vector::from((1, 2)));
+    assert_eq!(cells.len(), 6);
+    assert_eq!(cells[0], Rect::from((0, 0, 10, 4)));
+    assert_eq!(cells[1], Rect::from((11, 0, 10, 4)));
+    assert_eq!(cells[2], Rect::from((22, 0, 10, 4)));
+    assert_eq!(cells[3], Rect::from((0, 6, 10, 4)));
+    assert_eq!(cells[5].right(), rect.right());
+    assert_eq!(cells[5].top(), rect.top());
+    assert!(rect.split_grid_spaced(0, 2, Vector::default()).is_empty());
+    assert!(rect
+        .split_grid_spaced(65537, 1, Vector::default())
+        .is_empty());
+    assert!(rect
+        .split_grid_spaced(1, 40000, Vector::default())
+        .is_empty());
+}
+
+#[test]
+fn test_vector_tuple_operators() {
+    let pos = Vector::from((3, 4));
+    assert_eq!(pos + (1, 0), Vector::from((4, 4)));
+    assert_eq!(pos - (1, 2), Vector::from((2, 2)));
+    assert_eq!(pos * (2, -1), Vector::from((6, -4)));
+    assert_eq!(pos + (0.5, 0.25), Vector::from((3.5, 4.25)));
+    assert_eq!(pos - (0.5, 1.0), Vector::from((2.5, 3.0)));
+    assert_eq!(pos * (0.5, 2.0), Vector::from((1.5, 8.0)));
+}
+
+#[test]
+fn test_rect_try_new() {
+    let pos = Vector::from((1, 2));
+    assert_eq!(
+        Rect::try_new(pos, Vector::from((3, 4))),
+        Some(Rect::new(pos, Vector::from((3, 4))))
+    );
+    assert_eq!(
+        Rect::try_new(pos, Vector::from((0, 0))),
+        Some(Rect::new(pos, Vector::from((0, 0))))
+    );
+    assert_eq!(Rect::try_new(pos, Vector::from((-1, 4))), None);
+    assert_eq!(Rect::try_new(pos, Vector::from((3, -4))), None);
+}
+
+#[test]
+fn test_slide() {
+    let velocity = Vector::from((3, -2));
+    assert_eq!(velocity.slide(&Vector::up()), Vector::from((3, 0)));
+    assert_eq!(velocity.slide(&Vector::left()), Vector::from((0, -2)));
+    assert_eq!(
+        Vector::from((0, 5)).slide(&Vector::up()),
+        Vector::from((0, 0))
+    );
+}
+
+#[test]
+fn test_lerp_corners() {
+    let from = Rect::from((0, 0, 4, 4));
+    let to = Rect::from((4, 2, 8, 2));
+    assert_eq!(from.lerp_corners(&to, Fp::zero()), from);
+    assert_eq!(from.lerp_corners(&to, Fp::one()), to);
+    assert_eq!(
+        from.lerp_corners(&to, Fp::from(0.5)),
+        Rect::from((2, 1, 6, 3))
+    );
+}
+
+#[test]
+fn test_vector_shifts() {
+    let v = Vector::from((3, -5));
+    assert_eq!(v.shl(2), Vector::from((12, -20)));
+    assert_eq!(v.shr(1), Vector::from((1.5, -2.5)));
+    assert_eq!(v.shl(2).shr(2), v);
+    assert_eq!(v.shl(32), Vector::default());
+    assert_eq!(Vector::from((1, 0)).shr(40), Vector::default());
+}
+
+#[test]
+fn test_clamp_size() {
+    let min = Vector::from((2, 2));
+    let max = Vector::from((8, 8));
+    assert_eq!(
+        Rect::from((1, 1, 10, 1)).clamp_size(min, max),
+        Rect::from((1, 1, 8, 2))
+    );
+    assert_eq!(
+        Rect::from((1, 1, 4, 5)).clamp_size(min, max),
+        Rect::from((1, 1, 4, 5))
+    );
+}
+
+#[test]
+fn test_normalize_fast_vectors() {
+    let vectors = [
+        (Vector::from((3, 4)), (39321, 52428)),
+        (Vector::from((1, 1)), (46341, 46341)),
+        (Vector::from((-2, 0)), (-65536, 0)),
+        (Vector::from((0, 7)), (0, 65536)),
+        (Vector::from((1, -2)), (29308, -58617)),
+        (Vector::from((-5, -12)), (-25206, -60494)),
+        (Vector::from((0.5, 0.25)), (58618, 29309)),
+        (Vector::from((100, 1)), (65532, 655)),
+    ];
+    for (vector, (x, y)) in vectors {
+        assert_eq!(
+            vector.normalize_fast(),
+            Some(Vector::new(Fp::from_raw(x), Fp::from_raw(y))),
+            "normalizing {vector}"
+        );
+    }
+    assert_eq!(Vector::default().normalize_fast(), None);
+}
+
+#[test]
+fn test_raycast_normals() {
+    let rect = Rect::from((0, 0, 4, 4));
+    let from_left = Ray::new(Vector::from((-2, 1)), Vector::from((1, 0)));
+    assert_eq!(
+        rect.raycast(&from_left),
+        Some((Fp::from(2), Vector::left()))
+    );
+
+    let from_right = Ray::new(Vector::from((10, 1)), Vector::from((-2, 0)));
+    assert_eq!(
+        rect.raycast(&from_right),
+        Some((Fp::from(3), Vector::right()))
+    );
+
+    let from_above = Ray::new(Vector::from((3, 8)), Vector::from((-1, -2)));
+    let (t, normal) = rect.raycast(&from_above).unwrap();
+    assert_eq!(t, Fp::from(2));
+    assert_eq!(normal, Vector::up());
+    assert_eq!(from_above.point_at(t), Vector::from((1, 4)));
+
+    let from_below = Ray::new(Vector::from((2, -1)), Vector::from((0, 1)));
+    assert_eq!(
+        rect.raycast(&from_below),
+        Some((Fp::from(1), Vector::down()))
+    );
+}
+
+#[test]
+fn test_raycast_misses() {
+    let rect = Rect::from((0, 0, 4, 4));
+    let parallel_outside = Ray::new(Vector::from((-2, 5)), Vector::from((1, 0)));
+    assert_eq!(rect.raycast(&parallel_outside), None);
+
+    let pointing_away = Ray::new(Vector::from((-2, 1)), Vector::from((-1, 0)));
+    assert_eq!(rect.raycast(&pointing_away), None);
+
+    let passing_by = Ray::new(Vector::from((-2, 1)), Vector::from((1, 3)));
+    assert_eq!(rect.raycast(&passing_by), None);
+
+    let inside = Ray::new(Vector::from((2, 2)), Vector::from((1, 0)));
+    assert_eq!(rect.raycast(&inside), None);
+}
+
+#[test]
+fn test_remap() {
+    let stick = Vector::from((96, 16));
+    let remapped = stick.remap(
+        Vector::from((0, 0)),
+        Vector::from((128, 64)),
+        Vector::from((-1, 0)),
+        Vector::from((1, 1)),
+    );
+    assert_eq!(remapped, Vector::from((0.5, 0.25)));
+
+    let empty_range = Vector::from((3, 3)).remap(
+        Vector::from((1, 1)),
+        Vector::from((1, 2)),
+        Vector::from((5, 0)),
+        Vector::from((6, 10)),
+    );
+    assert_eq!(empty_range, Vector::from((5, 20)));
+}
+
+#[test]
+fn test_rect_rounding() {
+    let rect = Rect::from((0.4, 1.6, 2.4, 2.4));
+    assert_eq!(rect.floor(), Rect::from((0, 1, 2, 2)));
+    assert_eq!(rect.ceil(), Rect::from((1, 2, 3, 3)));
+    assert_eq!(rect.round(), Rect::from((0, 2, 2, 2)));
+    assert_eq!(rect.round_edges(), Rect::from((0, 2, 3, 2)));
+}
+
+#[test]
+fn test_rect_round_edges_keeps_shared_edges() {
+    let first = Rect::from((0.0, 0.0, 2.6, 1.0));
+    let second = Rect::from((2.6, 0.0, 2.6, 1.0));
+    assert_eq!(first.round_edges().right(), second.round_edges().left());
+}
+
+#[test]
+fn test_from_ints() {
+    const OFFSET: Vector = Vector::from_ints(-3, 12);
+    assert_eq!(OFFSET, Vector::from((-3, 12)));
+    assert_eq!(Vector::from_ints(0, 0), Vector::default());
+
+    const LIMITS: Vector = Vector::from_ints(32767, -32768);
+    assert_eq!(LIMITS, Vector::from((32767, -32768)));
+}
+
+#[test]
+#[should_panic(expected = "i16 range")]
+fn test_from_ints_out_of_range() {
+    let _ = Vector::from_ints(40000, 0);
+}
+
+#[test]
+fn test_scale_about() {
+    let rect = Rect::from((2, 2, 4, 4));
+    assert_eq!(
+        rect.scale_about(Fp::from(2), Vector::from((4, 4))),
+        Rect::from((0, 0, 8, 8))
+    );
+    assert_eq!(
+        rect.scale_about(Fp::from(0.5), Vector::from((2, 2))),
+        Rect::from((2, 2, 2, 2))
+    );
+    assert_eq!(
+        rect.scale_about(Fp::from(2), Vector::from((0, 0))),
+        Rect::from((4, 4, 8, 8))
+    );
+}
+
+#[test]
+fn test_closest_point_on_segment_info() {
+    let a = Vector::from((0, 0));
+    let b = Vector::from((10, 0));
+    assert_eq!(
+        Vector::from((4, 3)).closest_point_on_segment_info(&a, &b),
+        (Vector::from((4, 0)), false)
+    );
+    assert_eq!(
+        Vector::from((10, 3)).closest_point_on_segment_info(&a, &b),
+        (Vector::from((10, 0)), false)
+    );
+    assert_eq!(
+        Vector::from((-2, 1)).closest_point_on_segment_info(&a, &b),
+        (a, true)
+    );
+    assert_eq!(
+        Vector::from((12, -1)).closest_point_on_segment_info(&a, &b),
+        (b, true)
+    );
+}
+
+#[test]
+fn test_closest_point_on_long_segment_info() {
+    let a = Vector::from((0, 0));
+    let b = Vector::from((100, 0));
+    assert_eq!(
+        Vector::from((50, 0)).closest_point_on_segment_info(&a, &b),
+        (Vector::from((50, 0)), false)
+    );
+    assert_eq!(
+        Vector::from((75, -20)).closest_point_on_segment_info(&a, &b),
+        (Vector::from((75, 0)), false)
+    );
+    assert_eq!(
+        Vector::from((250, 3)).closest_point_on_segment_info(&a, &b),
+        (b, true)
+    );
+    let c = Vector::from((-150, 200));
+    assert_eq!(
+        Vector::from((0, 0))
+            .closest_point_on_segment_info(&Vector::from((-300, 0)), &Vector::from((300, 0))),
+        (Vector::from((0, 0)), false)
+    );
+    assert_eq!(c.closest_point_on_segment_info(&a, &b), (a, true));
+}
+
+#[test]
+fn test_signed_area_swept() {
+    let a = Vector::from((4, 0));
+    let b = Vector::from((0, 3));
+    assert_eq!(a.signed_area_swept(&b), Fp::from(6));
+    assert_eq!(b.signed_area_swept(&a), Fp::from(-6));
+    assert_eq!(a.signed_area_swept(&a), Fp::zero());
+}
+
+#[test]
+fn test_rect_local_space() {
+    let rect = Rect::from((10, -5, 20, 20));
+    let point = Vector::from((12, 3));
+    let local = rect.to_local(&point);
+    assert_eq!(local, Vector::from((2, 8)));
+    assert_eq!(rect.to_world(&local), point);
+}
+
+#[test]
+fn test_size_conversions() {
+    let vector = Vector::from((7, 9));
+    let size = Size::from(vector);
+    assert_eq!(size, Size::new(Fp::from(7), Fp::from(9)));
+    assert_eq!(Vector::from(size), vector);
+    assert_eq!(Rect::new(Vector::default(), vector).size(), size);
+}
+
+#[test]
+fn test_contains_point_inclusive() {
+    let rect = Rect::new(Vector::from((0, 0)), Vector::from((10, 10)));
+    assert!(rect.contains_point_inclusive(&Vector::from((10, 10))));
+    assert!(rect.contains_point_inclusive(&Vector::from((0, 5))));
+    assert!(!rect.contains_point_inclusive(&Vector::from((11, 5))));
+}
+
+#[test]
+fn test_contains_segment() {
+    let rect = Rect::new(Vector::from((0, 0)), Vector::from((10, 10)));
+    assert!(rect.contains_segment(&Vector::from((1, 1)), &Vector::from((10, 10))));
+    assert!(!rect.contains_segment(&Vector::from((1, 1)), &Vector::from((12, 5))));
+    assert!(!rect.contains_segment(&Vector::from((-1, 5)), &Vector::from((11, 5))));
+}
+
+#[test]
+fn test_approx_zero() {
+    let epsilon = Fp::from(0.1);
+    assert!(Vector::default().approx_zero(epsilon));
+    assert!(Vector::from((0.05, -0.05)).approx_zero(epsilon));
+    assert!(!Vector::from((0.2, 0.0)).approx_zero(epsilon));
+}
+
+#[test]
+fn test_nine_slice() {
+    let rect = Rect::from((0, 0, 20, 10));
+    let slices = rect.nine_slice((Fp::from(2), Fp::from(3), Fp::from(1), Fp::from(4)));
+    assert_eq!(slices[0], Rect::from((0, 0, 2, 4)));
+    assert_eq!(slices[1], Rect::from((2, 0, 15, 4)));
+    assert_eq!(slices[2], Rect::from((17, 0, 3, 4)));
+    assert_eq!(slices[4], Rect::from((2, 4, 15, 5)));
+    assert_eq!(slices[8], Rect::from((17, 9, 3, 1)));
+    let total_area = slices
+        .iter()
+        .fold(Fp::zero(), |sum, slice| sum + slice.area());
+    assert_eq!(total_area, rect.area());
+}
+
+#[test]
+fn test_round_preserving_sum() {
+    let values = [
+        Vector::from((1.5, 0.25)),
+        Vector::from((1.5, 0.25)),
+        Vector::from((1.0, 0.75)),
+        Vector::from((-0.5, 0.75)),
+    ];
+    let rounded = Vector::round_preserving_sum(&values);
+    assert_eq!(
+        rounded,
+        [
+            Vector::from((2, 0)),
+            Vector::from((2, 0)),
+            Vector::from((1, 1)),
+            Vector::from((-1, 1)),
+        ]
+    );
+    let total: Vector = rounded
+        .iter()
+        .fold(Vector::default(), |sum, value| sum + *value);
+    assert_eq!(total, Vector::from((4, 2)));
+    assert!(Vector::round_preserving_sum(&[]).is_empty());
+}
+
+#[test]
+fn test_rotator_matches_rotate() {
+    let angle = Fp::FRAC_PI_2;
+    let rotate = Vector::rotator(angle);
+    for vector in [
+        Vector::from((1, 0)),
+        Vector::from((3, -2)),
+        Vector::from((-4, 5)),
+    ] {
+        assert_eq!(rotate(vector), vector.rotate(angle));
+    }
+}
+
+#[test]
+fn test_intersection_with_iou() {
+    let rect1 = Rect::from((0, 0, 4, 4));
+    let rect2 = Rect::from((2, 0, 4, 4));
+    let (intersection, iou) = rect1.intersection_with_iou(&rect2).unwrap();
+    assert_eq!(intersection, Rect::from((2, 0, 2, 4)));
+    assert_eq!(iou, Fp::from(8) / Fp::from(24));
+
+    assert_eq!(
+        rect1.intersection_with_iou(&rect1),
+        Some((rect1, Fp::one()))
+    );
+    assert_eq!(
+        rect1.intersection_with_iou(&Rect::from((10, 10, 1, 1))),
+        None
+    );
+
+    let tiny = Rect::from((0.0, 0.0, 0.002, 0.002));
+    assert_eq!(tiny.intersection_with_iou(&tiny), Some((tiny, Fp::one())));
+
+    let large1 = Rect::from((0, 0, 1000, 1000));
+    let large2 = Rect::from((500, 0, 1000, 1000));
+    let (_, iou) = large1.intersection_with_iou(&large2).unwrap();
+    assert_eq!(iou, Fp::one() / Fp::from(3));
+}
+
+#[test]
+fn test_from_barycentric() {
+    let a = Vector::from((0, 0));
+    let b = Vector::from((6, 0));
+    let c = Vector::from((0, 3));
+    assert_eq!(
+        Vector::from_barycentric(&a, &b, &c, Fp::zero(), Fp::zero()),
+        a
+    );
+    assert_eq!(
+        Vector::from_barycentric(&a, &b, &c, Fp::one(), Fp::zero()),
+        b
+    );
+    assert_eq!(
+        Vector::from_barycentric(&a, &b, &c, Fp::zero(), Fp::one()),
+        c
+    );
+    assert_eq!(
+        Vector::from_barycentric(&a, &b, &c, Fp::from(0.5), Fp::from(0.5)),
+        Vector::from((3.0, 1.5))
+    );
+}
+
+#[test]
+fn test_union_opt() {
+    let rect = Rect::from((5, 5, 2, 2));
+    assert_eq!(Rect::union_opt(None, rect), Some(rect));
+    assert_eq!(
+        Rect::union_opt(Some(rect), Rect::from((1, 6, 1, 1))),
+        Some(Rect::from((1, 5, 6, 2)))
+    );
+    let empty: [Rect; 0] = [];
+    assert_eq!(
+        empty
+            .iter()
+            .fold(None, |acc, rect| Rect::union_opt(acc, *rect)),
+        None
+    );
+}
+
+#[test]
+fn test_cross_sign() {
+    let right = Vector::right();
+    assert_eq!(right.cross_sign(&Vector::up()), 1);
+    assert_eq!(right.cross_sign(&Vector::down()), -1);
+    assert_eq!(right.cross_sign(&Vector::from((-3, 0))), 0);
+
+    let tiny = Vector::new(Fp::from_raw(1), Fp::from_raw(1));
+    let nudged = Vector::new(Fp::from_raw(1), Fp::from_raw(2));
+    assert_eq!(tiny.cross_sign(&nudged), 1);
+}
+
+#[test]
+fn test_border() {
+    let rect = Rect::from((0, 0, 10, 6));
+    let [bottom, top, left, right] = rect.border(Fp::from(1));
+    assert_eq!(bottom, Rect::from((0, 0, 10, 1)));
+    assert_eq!(top, Rect::from((0, 5, 10, 1)));
+    assert_eq!(left, Rect::from((0, 1, 1, 4)));
+    assert_eq!(right, Rect::from((9, 1, 1, 4)));
+    let border_area = bottom.area() + top.area() + left.area() + right.area();
+    assert_eq!(
+        border_area,
+        rect.area() - rect.contracted(Vector::from((1, 1))).area()
+    );
+}
+
+#[test]
+fn test_border_thicker_than_rect() {
+    let [bottom, top, left, right] = Rect::from((0, 0, 10, 4)).border(Fp::from(3));
+    assert_eq!(bottom, Rect::from((0, 0, 10, 2)));
+    assert_eq!(top, Rect::from((0, 2, 10, 2)));
+    assert_eq!(left, Rect::from((0, 2, 3, 0)));
+    assert_eq!(right, Rect::from((7, 2, 3, 0)));
+    assert!(!bottom.is_overlapping(top));
+
+    let [bottom, top, left, right] = Rect::from((0, 0, 4, 10)).border(Fp::from(3));
+    assert_eq!(bottom, Rect::from((0, 0, 4, 3)));
+    assert_eq!(top, Rect::from((0, 7, 4, 3)));
+    assert_eq!(left, Rect::from((0, 3, 2, 4)));
+    assert_eq!(right, Rect::from((2, 3, 2, 4)));
+    assert!(!left.is_overlapping(right));
+}
+
+#[test]
+fn test_direction_to() {
+    let from = Vector::from((1, 1));
+    assert_eq!(from.direction_to(&Vector::from((1, 6))), Some(Vector::up()));
+    assert_eq!(
+        from.direction_to(&Vector::from((-3, 1))),
+        Some(Vector::left())
+    );
+    assert_eq!(from.direction_to(&from), None);
+}
+
+#[test]
+fn test_pack() {
+    let bin = Rect::from((0, 0, 10, 6));
+    let sizes = [
+        Vector::from((4, 2)),
+        Vector::from((4, 3)),
+        Vector::from((4, 2)),
+        Vector::from((11, 1)),
+        Vector::from((6, 4)),
+        Vector::from((2, 1)),
+    ];
+    assert_eq!(
+        Rect::pack(bin, &sizes),
+        [
+            Some(Vector::from((0, 0))),
+            Some(Vector::from((4, 0))),
+            Some(Vector::from((0, 3))),
+            None,
+            None,
+            Some(Vector::from((4, 3))),
+        ]
+    );
+}
+
+#[test]
+fn test_ease_lerp() {
+    let from = Vector::from((0, 0));
+    let to = Vector::from((8, 4));
+    let half = Fp::from(0.5);
+    assert_eq!(
+        from.ease_lerp(&to, half, Easing::Linear),
+        Vector::from((4, 2))
+    );
+    assert_eq!(
+        from.ease_lerp(&to, half, Easing::QuadIn),
+        Vector::from((2, 1))
+    );
+    assert_eq!(
+        from.ease_lerp(&to, half, Easing::QuadOut),
+        Vector::from((6, 3))
+    );
+    assert_eq!(
+        from.ease_lerp(&to, half, Easing::CubicIn),
+        Vector::from((1.0, 0.5))
+    );
+    assert_eq!(
+        from.ease_lerp(&to, half, Easing::QuadInOut),
+        Vector::from((4, 2))
+    );
+    for easing in [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+    ] {
+        assert_eq!(from.ease_lerp(&to, Fp::zero(), easing), from);
+        assert_eq!(from.ease_lerp(&to, Fp::one(), easing), to);
+    }
+}
+
+#[test]
+fn test_resize_edge() {
+    let rect = Rect::from((2, 2, 6, 4));
+    let delta = Fp::from(1);
+    assert_eq!(
+        rect.resize_edge(Edge::Left, delta),
+        Rect::from((3, 2, 5, 4))
+    );
+    assert_eq!(
+        rect.resize_edge(Edge::Right, delta),
+        Rect::from((2, 2, 7, 4))
+    );
+    assert_eq!(
+        rect.resize_edge(Edge::Bottom, -delta),
+        Rect::from((2, 1, 6, 5))
+    );
+    assert_eq!(
+        rect.resize_edge(Edge::Top, -delta),
+        Rect::from((2, 2, 6, 3))
+    );
+    assert_eq!(rect.resize_edge(Edge::Left, -delta).right(), rect.right());
+}
+
+#[test]
+fn test_rect_diagonal() {
+    let rect = Rect::from((1, 1, 6, 8));
+    assert_eq!(rect.diagonal(), Vector::from((6, 8)));
+    assert_eq!(rect.diagonal_len(), Fp::from(10));
+}
+
+#[test]
+fn test_vector_component_accessors() {
+    let mut v = Vector::from((1, 2));
+    *v.x_mut() += Fp::from(3);
+    *v.y_mut() = Fp::from(-1);
+    assert_eq!(v, Vector::from((4, -1)));
+
+    for axis in 0..2 {
+        *v.axis_mut(axis) += Fp::one();
+    }
+    assert_eq!(v.axis(0), Fp::from(5));
+    assert_eq!(v.axis(1), Fp::zero());
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_vector_axis_out_of_range() {
+    let _ = Vector::default().axis(2);
+}
+
+#[test]
+fn test_is_visible_in() {
+    let viewport = Rect::from((0, 0, 100, 50));
+    assert!(Rect::from((10, 10, 5, 5)).is_visible_in(&viewport));
+    assert!(Rect::from((-5, -5, 10, 10)).is_visible_in(&viewport));
+    assert!(!Rect::from((-10, 10, 10, 5)).is_visible_in(&viewport));
+    assert!(!Rect::from((100, 10, 5, 5)).is_visible_in(&viewport));
+    assert!(!Rect::from((10, 50, 5, 5)).is_visible_in(&viewport));
+    assert!(!Rect::from((200, 10, 5, 5)).is_visible_in(&viewport));
+}
+
+#[test]
+fn test_basis_from_angle() {
+    let (right, up) = Vector::basis_from_angle(Fp::zero());
+    assert_eq!(right, Vector::right());
+    assert_eq!(up, Vector::up());
+
+    let angle = Fp::from(0.7);
+    let (right, up) = Vector::basis_from_angle(angle);
+    assert!(right.dot(&up).abs() < Fp::from(0.001));
+    assert_eq!(right, Vector::right().rotate(angle));
+    assert_eq!(up, Vector::up().rotate(angle));
+}
+
+#[test]
+fn test_cell_at() {
+    let map = Rect::from((-8, 0, 32, 16));
+    let cell = Vector::from((8, 4));
+    assert_eq!(
+        map.cell_at(&Vector::from((-8, 0)), cell),
+        Some(IntVector::new(0, 0))
+    );
+    assert_eq!(
+        map.cell_at(&Vector::from((-0.5, 3.9)), cell),
+        Some(IntVector::new(0, 0))
+    );
+    assert_eq!(
+        map.cell_at(&Vector::from((0, 4)), cell),
+        Some(IntVector::new(1, 1))
+    );
+    assert_eq!(
+        map.cell_at(&Vector::from((23.9, 15.9)), cell),
+        Some(IntVector::new(3, 3))
+    );
+    assert_eq!(map.cell_at(&Vector::from((24, 5)), cell), None);
+    assert_eq!(map.cell_at(&Vector::from((-9, 5)), cell), None);
+
+    let inside = Vector::from((1, 1));
+    assert_eq!(map.cell_at(&inside, Vector::from((0, 4))), None);
+    assert_eq!(map.cell_at(&inside, Vector::from((8, 0))), None);
+    assert_eq!(map.cell_at(&inside, Vector::from((-8, 4))), None);
+}
+
+#[test]
+fn test_clamp_to_ellipse() {
+    let radii = Vector::from((4, 2));
+    let inside = Vector::from((2, 1));
+    assert_eq!(inside.clamp_to_ellipse(radii), inside);
+    assert_eq!(
+        Vector::from((8, 0)).clamp_to_ellipse(radii),
+        Vector::from((4, 0))
+    );
+    assert_eq!(
+        Vector::from((0, -6)).clamp_to_ellipse(radii),
+        Vector::from((0, -2))
+    );
+
+    let clamped = Vector::from((4, 2)).clamp_to_ellipse(radii);
+    let on_ellipse = (clamped.x / radii.x) * (clamped.x / radii.x)
+        + (clamped.y / radii.y) * (clamped.y / radii.y);
+    assert!((on_ellipse - Fp::one()).abs() < Fp::from(0.01));
+    assert!(clamped.cross(&Vector::from((4, 2))).abs() < Fp::from(0.01));
+
+    assert_eq!(
+        Vector::from((3, 5)).clamp_to_ellipse(Vector::from((0, 2))),
+        Vector::from((0, 2))
+    );
+}
+
+#[test]
+fn test_outline_strip() {
+    let strip = Rect::from((1, 1, 2, 3)).outline_strip();
+    assert_eq!(
+        strip,
+        [
+            Vector::from((1, 1)),
+            Vector::from((3, 1)),
+            Vector::from((3, 4)),
+            Vector::from((1, 4)),
+            Vector::from((1, 1)),
+        ]
+    );
+}
+
+#[test]
+fn test_vector_compound_assignment() {
+    let original = Vector::from((3, -7));
+    let other = Vector::from((2, 5));
+    let mut v = original;
+    v += other;
+    v -= other;
+    assert_eq!(v, original);
+
+    let mut scaled = original;
+    scaled *= Fp::from(2);
+    assert_eq!(scaled, original * Fp::from(2));
+    scaled /= Fp::from(2);
+    assert_eq!(scaled, original);
+
+    let mut component_wise = original;
+    component_wise *= other;
+    assert_eq!(component_wise, original * other);
+    component_wise /= other;
+    assert_eq!(component_wise, original);
+}
+
+#[test]
+fn test_is_clockwise_from() {
+    assert!(Vector::right().is_clockwise_from(&Vector::up()));
+    assert!(!Vector::up().is_clockwise_from(&Vector::right()));
+    assert!(Vector::down().is_clockwise_from(&Vector::right()));
+    assert!(!Vector::left().is_clockwise_from(&Vector::right()));
+    assert!(!Vector::right().is_clockwise_from(&Vector::from((5, 0))));
+}
+
+#[test]
+fn test_distance() {
+    let a = Vector::from((1, 1));
+    let b = Vector::from((4, 5));
+    assert_eq!(a.sqr_distance(&b), Fp::from(25));
+    assert_eq!(a.distance(&b), Fp::from(5));
+    assert_eq!(b.distance(&a), Fp::from(5));
+    assert_eq!(a.distance(&a), Fp::zero());
+    assert_eq!(a.sqr_distance(&a), Fp::zero());
+}
+
+#[test]
+fn test_lerp() {
+    let a = Vector::from((-3, 10));
+    let b = Vector::from((5, 4));
+    assert_eq!(a.lerp(&b, Fp::zero()), a);
+    assert_eq!(a.lerp(&b, Fp::one()), b);
+    let midpoint = a.lerp(&b, Fp::from(0.5));
+    assert_eq!(midpoint, Vector::from((1, 7)));
+    assert_eq!(midpoint, (a + b) / 2);
+    assert_eq!(a.lerp(&b, Fp::from(2)), Vector::from((13, -2)));
+}
+
+#[test]
+fn test_sort_by_angle() {
+    let center = Vector::from((1, 1));
+    let mut points = [
+        Vector::from((1, 0)),
+        Vector::from((0, 1)),
+        Vector::from((3, 1)),
+        Vector::from((2, 1)),
+        Vector::from((1, 2)),
+        Vector::from((2, 0)),
+        Vector::from((1, 1)),
+        Vector::from((0, 2)),
+    ];
+    Vector::sort_by_angle(&mut points, center);
+    assert_eq!(
+        points,
+        [
+            Vector::from((1, 1)),
+            Vector::from((2, 1)),
+            Vector::from((3, 1)),
+            Vector::from((1, 2)),
+            Vector::from((0, 2)),
+            Vector::from((0, 1)),
+            Vector::from((1, 0)),
+            Vector::from((2, 0)),
+        ]
+    );
+}
+
+#[test]
+fn test_from_center_size_aspect() {
+    let rect = Rect::from_center_size_aspect(Vector::from((0, 0)), Fp::from(9), Fp::from(2));
+    assert_eq!(rect, Rect::from((-9.0, -4.5, 18.0, 9.0)));
+    assert_eq!(rect.aspect_ratio(), Some(Fp::from(2)));
+}
+
+#[test]
+fn test_reflect() {
+    assert_eq!(
+        Vector::from((1, -1)).reflect(&Vector::up()),
+        Vector::from((1, 1))
+    );
+
+    let normal = Vector::from((1, 1)).normalize().unwrap();
+    let along_surface = Vector::from((3, -3));
+    let reflected = along_surface.reflect(&normal);
+    assert!((reflected - along_surface).sqr_len() < Fp::from(0.001));
+}
+
+#[test]
+fn test_damp() {
+    let from = Vector::from((0, 0));
+    let to = Vector::from((10, -10));
+    assert_eq!(from.damp(&to, Fp::from(5), Fp::zero()), from);
+
+    let one_step = from.damp(&to, Fp::from(2), Fp::from(0.5));
+    let expected = 10.0 * (1.0 - (-1.0f32).exp());
+    assert!((one_step.x - Fp::from(expected)).abs() < Fp::from(0.001));
+    assert_eq!(one_step.y, -one_step.x);
+
+    let two_steps =
+        from.damp(&to, Fp::from(2), Fp::from(0.25))
+            .damp(&to, Fp::from(2), Fp::from(0.25));
+    assert!((two_steps - one_step).sqr_len() < Fp::from(0.001));
+
+    assert!((from.damp(&to, Fp::from(100), Fp::one()) - to).sqr_len() < Fp::from(0.001));
+}
+
+#[test]
+fn test_project_onto_and_reject_from() {
+    let v = Vector::from((2, 2));
+    let axis = Vector::from((1, 0));
+    let projection = v.project_onto(&axis).unwrap();
+    let rejection = v.reject_from(&axis).unwrap();
+    assert_eq!(projection, Vector::from((2, 0)));
+    assert_eq!(rejection, Vector::from((0, 2)));
+    assert_eq!(projection + rejection, v);
+
+    let diagonal = Vector::from((3, 1));
+    let onto = Vector::from((2, 2));
+    let sum = diagonal.project_onto(&onto).unwrap() + diagonal.reject_from(&onto).unwrap();
+    assert_eq!(sum, diagonal);
+
+    assert_eq!(v.project_onto(&Vector::default()), None);
+    assert_eq!(v.reject_from(&Vector::default()), None);
+}
+
+#[test]
+fn test_project_onto_large_vectors() {
+    let long = Vector::from((100, 0));
+    assert_eq!(long.project_onto(&long), Some(long));
+
+    let v = Vector::from((60, 80));
+    let axis = Vector::from((0, 120));
+    assert_eq!(v.project_onto(&axis), Some(Vector::from((0, 80))));
+    assert_eq!(v.reject_from(&axis), Some(Vector::from((60, 0))));
+}
+
+#[test]
+fn test_zoom_at_keeps_focus_in_place() {
+    let view = Rect::from((0, 0, 16, 8));
+    let focus = Vector::from((12, 2));
+    let zoomed = view.zoom_at(Fp::from(2), focus);
+    assert_eq!(zoomed, Rect::from((6, 1, 8, 4)));
+
+    let screen_position = |view: &Rect| view.to_local(&focus) / view.size;
+    assert_eq!(screen_position(&zoomed), screen_position(&view));
+
+    assert_eq!(zoomed.zoom_at(Fp::from(0.5), focus), view);
+}
+
+#[test]
+fn test_perpendicular() {
+    assert_eq!(Vector::right().perpendicular(), Vector::up());
+    assert_eq!(Vector::up().perpendicular(), Vector::left());
+    assert_eq!(Vector::right().perpendicular_cw(), Vector::down());
+    let v = Vector::from((3, -7));
+    assert_eq!(v.perpendicular().perpendicular_cw(), v);
+    assert_eq!(v.dot(&v.perpendicular()), Fp::zero());
+}
+
+#[test]
+fn test_center_of_mass() {
+    let a = Vector::from((0, 0));
+    let b = Vector::from((12, 4));
+    assert_eq!(
+        Vector::center_of_mass(&a, Fp::from(3), &b, Fp::from(1)),
+        Vector::from((3, 1))
+    );
+
+    let heavy = Fp::from(30000);
+    assert_eq!(
+        Vector::center_of_mass(&a, heavy, &b, heavy),
+        Vector::from((6, 2))
+    );
+
+    assert_eq!(
+        Vector::center_of_mass(&a, Fp::zero(), &b, Fp::zero()),
+        Vector::from((6, 2))
+    );
+}
+
+#[test]
+fn test_angle() {
+    let epsilon = Fp::from(0.001);
+    assert_eq!(Vector::right().angle(), Fp::zero());
+    assert!((Vector::up().angle() - Fp::FRAC_PI_2).abs() < epsilon);
+    assert!((Vector::down().angle() + Fp::FRAC_PI_2).abs() < epsilon);
+    assert!((Vector::left().angle() - Fp::FRAC_PI_2 * Fp::from(2)).abs() < epsilon);
+    assert!((Vector::from((1, 1)).angle() - Fp::FRAC_PI_2 / Fp::from(2)).abs() < epsilon);
+    assert!(
+        (Vector::from((-3, -3)).angle() + Fp::from(3) * Fp::FRAC_PI_2 / Fp::from(2)).abs()
+            < epsilon
+    );
+}
+
+#[test]
+fn test_angle_between() {
+    let epsilon = Fp::from(0.001);
+    assert!((Vector::right().angle_between(&Vector::up()) - Fp::FRAC_PI_2).abs() < epsilon);
+    assert!((Vector::up().angle_between(&Vector::right()) + Fp::FRAC_PI_2).abs() < epsilon);
+    assert_eq!(
+        Vector::right().angle_between(&Vector::from((5, 0))),
+        Fp::zero()
+    );
+    assert!(Vector::right().angle_between(&Vector::left()) > Fp::zero());
+}
+
+#[test]
+fn test_letterbox_bars() {
+    let container = Rect::from((0, 0, 160, 90));
+
+    let wide = Rect::from((0, 0, 320, 90));
+    let bars = Rect::letterbox_bars(&wide, &container);
+    assert_eq!(
+        bars,
+        vec![
+            Rect::from((0.0, 0.0, 160.0, 22.5)),
+            Rect::from((0.0, 67.5, 160.0, 22.5))
+        ]
+    );
+
+    let tall = Rect::from((0, 0, 90, 90));
+    let bars = Rect::letterbox_bars(&tall, &container);
+    assert_eq!(
+        bars,
+        vec![Rect::from((0, 0, 35, 90)), Rect::from((125, 0, 35, 90))]
+    );
+
+    let same_aspect = Rect::from((10, 10, 16, 9));
+    assert!(Rect::letterbox_bars(&same_aspect, &container).is_empty());
+
+    let empty = Rect::from((0, 0, 0, 10));
+    assert!(Rect::letterbox_bars(&empty, &container).is_empty());
+}
+
+#[test]
+fn test_clamp_length() {
+    let v = Vector::from((3, 4));
+    let clamped = v.clamp_length_max(Fp::from(2.5));
+    assert_eq!(clamped, Vector::from((1.5, 2.0)));
+    assert_eq!(clamped.len(), Fp::from(2.5));
+
+    let short = Vector::from((1, 0));
+    assert_eq!(short.clamp_length_max(Fp::from(2.5)), short);
+    assert_eq!(
+        Vector::default().clamp_length_max(Fp::one()),
+        Vector::default()
+    );
+
+    assert_eq!(
+        short.clamp_length(Fp::from(2), Fp::from(4)),
+        Vector::from((2, 0))
+    );
+    assert_eq!(v.clamp_length(Fp::from(2), Fp::from(4)).len(), Fp::from(4));
+    assert_eq!(v.clamp_length(Fp::from(2), Fp::from(6)), v);
+    assert_eq!(
+        Vector::default().clamp_length(Fp::one(), Fp::from(2)),
+        Vector::default()
+    );
+}
+
+#[test]
+fn test_clamp_to_cone() {
+    let epsilon = Fp::from(0.01);
+    let half_angle = Fp::FRAC_PI_2 / Fp::from(2);
+    let axis = Vector::right();
+
+    let inside = Vector::from((2, 1));
+    assert_eq!(inside.clamp_to_cone(&axis, half_angle), inside);
+
+    let above = Vector::from((0, 2)).clamp_to_cone(&axis, half_angle);
+    assert!((above.x - Fp::from(core::f32::consts::SQRT_2)).abs() < epsilon);
+    assert!((above.y - Fp::from(core::f32::consts::SQRT_2)).abs() < epsilon);
+
+    let below = Vector::from((-2, -1)).clamp_to_cone(&axis, half_angle);
+    assert!((below.len() - Vector::from((-2, -1)).len()).abs() < epsilon);
+    assert!((below.angle() + half_angle).abs() < epsilon);
+
+    let along = Vector::from((3, 0));
+    assert_eq!(along.clamp_to_cone(&axis, Fp::zero()), along);
+    assert_eq!(
+        Vector::default().clamp_to_cone(&axis, half_angle),
+        Vector::default()
+    );
+    assert_eq!(inside.clamp_to_cone(&Vector::default(), half_angle), inside);
+}
+
+#[test]
+fn test_move_towards() {
+    let origin = Vector::default();
+    let target = Vector::from((10, 0));
+    assert_eq!(
+        origin.move_towards(&target, Fp::from(3)),
+        Vector::from((3, 0))
+    );
+    assert_eq!(origin.move_towards(&target, Fp::from(100)), target);
+    assert_eq!(origin.move_towards(&target, Fp::zero()), origin);
+    assert_eq!(origin.move_towards(&target, Fp::from(-1)), origin);
+
+    let mut position = origin;
+    for _ in 0..4 {
+        position = position.move_towards(&target, Fp::from(3));
+    }
+    assert_eq!(position, target);
+}
+
+#[test]
+fn test_scanlines() {
+    let rect = Rect::from((1, 2, 3, 2));
+    let spans: Vec<_> = rect.scanlines().collect();
+    assert_eq!(spans, vec![(2, 1, 4), (3, 1, 4)]);
+
+    // Pixel centers at 0.5 are covered, centers on the right or top edge are not.
+    let rect = Rect::from((0.5, 0.25, 1.0, 0.5));
+    assert_eq!(rect.scanlines().collect::<Vec<_>>(), vec![(0, 0, 1)]);
+
+    let left = Rect::from((-1.3, -0.7, 2.0, 1.6));
+    let right = Rect::from((0.7, -0.7, 1.4, 1.6));
+    let left_spans: Vec<_> = left.scanlines().collect();
+    let right_spans: Vec<_> = right.scanlines().collect();
+    assert_eq!(left_spans, vec![(-1, -1, 1), (0, -1, 1)]);
+    assert_eq!(right_spans, vec![(-1, 1, 2), (0, 1, 2)]);
+
+    assert_eq!(Rect::from((0.1, 0.0, 0.2, 5.0)).scanlines().count(), 0);
+}
+
+#[test]
+fn test_min_max() {
+    let a = Vector::from((1, 5));
+    let b = Vector::from((4, 2));
+    assert_eq!(a.min(&b), Vector::from((1, 2)));
+    assert_eq!(a.max(&b), Vector::from((4, 5)));
+    assert_eq!(a.min_component(), Fp::from(1));
+    assert_eq!(a.max_component(), Fp::from(5));
+    assert_eq!(Vector::from((-3, -7)).max_component(), Fp::from(-3));
+}
+
+#[test]
+fn test_rotated_corners() {
+    let rect = Rect::from((0, 0, 4, 2));
+    assert_eq!(rect.rotated_corners(Fp::zero()), rect.corners());
+
+    let epsilon = Fp::from(0.01);
+    let rotated = rect.rotated_corners(Fp::FRAC_PI_2);
+    let expected = [
+        Vector::from((3, -1)),
+        Vector::from((3, 3)),
+        Vector::from((1, 3)),
+        Vector::from((1, -1)),
+    ];
+    for (corner, expected) in rotated.iter().zip(expected.iter()) {
+        assert!((*corner - *expected).approx_zero(epsilon));
+    }
+}
+
+#[test]
+fn test_clamp() {
+    let min = Vector::from((0, 0));
+    let max = Vector::from((4, 4));
+    assert_eq!(
+        Vector::from((5, -3)).clamp(&min, &max),
+        Vector::from((4, 0))
+    );
+    assert_eq!(Vector::from((2, 3)).clamp(&min, &max), Vector::from((2, 3)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must not be greater than max")]
+fn test_clamp_inverted_bounds() {
+    let _ = Vector::from((1, 1)).clamp(&Vector::from((4, 0)), &Vector::from((0, 4)));
+}
+
+#[test]
+fn test_floor_div() {
+    let cell = Vector::from((16, 16));
+    assert_eq!(Vector::from((0, 0)).floor_div(cell), IntVector::new(0, 0));
+    assert_eq!(
+        Vector::from((15.9, 16.0)).floor_div(cell),
+        IntVector::new(0, 1)
+    );
+    assert_eq!(
+        Vector::from((-0.1, -16.0)).floor_div(cell),
+        IntVector::new(-1, -1)
+    );
+    assert_eq!(
+        Vector::from((-17, -33)).floor_div(cell),
+        IntVector::new(-2, -3)
+    );
+    assert_eq!(
+        Vector::from((5, -5)).floor_div(Vector::from((-2, -2))),
+        IntVector::new(-3, 2)
+    );
+}
+
+#[test]
+fn test_vector_rounding() {
+    let v = Vector::from((1.4, -1.6));
+    assert_eq!(v.floor(), Vector::from((1, -2)));
+    assert_eq!(v.ceil(), Vector::from((2, -1)));
+    assert_eq!(v.round(), Vector::from((1, -2)));
+    assert_eq!(v.sign(), Vector::from((1, -1)));
+    assert_eq!(Vector::from((0, 3)).sign(), Vector::from((0, 1)));
+}
+
+#[test]
+fn test_merge_adjacent() {
+    let row = [
+        Rect::from((0, 0, 1, 1)),
+        Rect::from((2, 0, 1, 1)),
+        Rect::from((1, 0, 1, 1)),
+    ];
+    assert_eq!(Rect::merge_adjacent(&row), vec![Rect::from((0, 0, 3, 1))]);
+
+    let block = [
+        Rect::from((0, 0, 2, 1)),
+        Rect::from((0, 1, 1, 1)),
+        Rect::from((1, 1, 1, 1)),
+    ];
+    assert_eq!(Rect::merge_adjacent(&block), vec![Rect::from((0, 0, 2, 2))]);
+
+    let partial = [Rect::from((0, 0, 2, 2)), Rect::from((2, 0, 1, 1))];
+    assert_eq!(Rect::merge_adjacent(&partial), partial.to_vec());
+
+    assert!(Rect::merge_adjacent(&[]).is_empty());
+}
+
+#[test]
+fn test_with_x_y() {
+    const V: Vector = Vector::from_ints(3, 4).with_x(Fp::zero());
+    assert_eq!(V.x, Fp::zero());
+    assert_eq!(V.y, Fp::from(4));
+
+    let v = Vector::from((3, 4)).with_y(Fp::from(-1));
+    assert_eq!(v, Vector::from((3, -1)));
+}
+
+#[test]
+fn test_angle_sector() {
+    assert_eq!(Vector::right().angle_sector(8), 0);
+    assert_eq!(Vector::up().angle_sector(4), 1);
+    assert_eq!(Vector::up().angle_sector(8), 2);
+    assert_eq!(Vector::left().angle_sector(8), 4);
+    assert_eq!(Vector::from((1, -1)).angle_sector(8), 7);
+    assert_eq!(Vector::from((10, -1)).angle_sector(8), 0);
+    assert_eq!(Vector::from((10, 1)).angle_sector(8), 0);
+    assert_eq!(Vector::from((-1, -10)).angle_sector(16), 12);
+    assert_eq!(Vector::default().angle_sector(8), 0);
+    assert_eq!(Vector::left().angle_sector(1), 0);
+}
+
+#[test]
+fn test_sum() {
+    let offsets = [
+        Vector::from((1, 2)),
+        Vector::from((3, 4)),
+        Vector::from((5, 6)),
+    ];
+    assert_eq!(offsets.iter().sum::<Vector>(), Vector::from((9, 12)));
+    assert_eq!(
+        offsets.iter().copied().sum::<Vector>(),
+        Vector::from((9, 12))
+    );
+    assert_eq!(
+        core::iter::empty::<Vector>().sum::<Vector>(),
+        Vector::default()
+    );
+}
+
+#[test]
+fn test_classify_point() {
+    let rect = Rect::from((0, 0, 4, 2));
+    assert_eq!(
+        rect.classify_point(&Vector::from((2, 1))),
+        PointClass::Inside
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((0, 1))),
+        PointClass::OnEdge(Edge::Left)
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((4, 1))),
+        PointClass::OnEdge(Edge::Right)
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((2, 0))),
+        PointClass::OnEdge(Edge::Bottom)
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((2, 2))),
+        PointClass::OnEdge(Edge::Top)
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((4, 2))),
+        PointClass::OnCorner
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((0, 0))),
+        PointClass::OnCorner
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((5, 1))),
+        PointClass::Outside
+    );
+    assert_eq!(
+        rect.classify_point(&Vector::from((2, -1))),
+        PointClass::Outside
+    );
+}
+
+#[test]
+fn test_array_and_tuple_conversions() {
+    let v = Vector::from((3.5, -2.0));
+
+    let array: [Fp; 2] = v.into();
+    assert_eq!(array, [Fp::from(3.5), Fp::from(-2)]);
+    assert_eq!(Vector::from(array), v);
+
+    let tuple: (Fp, Fp) = v.into();
+    assert_eq!(tuple, (Fp::from(3.5), Fp::from(-2)));
+    assert_eq!(Vector::new(tuple.0, tuple.1), v);
+}
+
+#[test]
+fn test_random_point() {
+    let rect = Rect::from((-2, 4, 8, 2));
+
+    let mut low = || 0;
+    assert_eq!(rect.random_point(&mut low), Vector::from((-2, 4)));
+
+    let mut half = || 1 << 31;
+    assert_eq!(rect.random_point(&mut half), Vector::from((2, 5)));
+
+    let mut high = || u32::MAX;
+    let point = rect.random_point(&mut high);
+    assert!(rect.contains_point(&point));
+
+    let mut state = 12345_u32;
+    let mut lcg = move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        state
+    };
+    for _ in 0..100 {
+        assert!(rect.contains_point(&rect.random_point(&mut lcg)));
+    }
+}
+
+#[test]
+fn test_index() {
+    let mut v = Vector::from((3, 4));
+    assert_eq!(v[0], Fp::from(3));
+    assert_eq!(v[1], Fp::from(4));
+
+    v[0] = Fp::from(-1);
+    v[1] += Fp::from(2);
+    assert_eq!(v, Vector::from((-1, 6)));
+
+    let sum = (0..2).fold(Fp::zero(), |sum, axis| sum + v[axis]);
+    assert_eq!(sum, Fp::from(5));
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_index_out_of_range() {
+    let v = Vector::from((3, 4));
+    let _ = v[2];
+}
+
+#[test]
+fn test_remove_component_along() {
+    let motion = Vector::from((3, 4));
+    assert_eq!(
+        motion.remove_component_along(&Vector::from((5, 0))),
+        Vector::from((0, 4))
+    );
+    assert_eq!(
+        motion.remove_component_along(&Vector::from((0, -2))),
+        Vector::from((3, 0))
+    );
+    assert_eq!(
+        Vector::from((2, 0)).remove_component_along(&Vector::from((1, 1))),
+        Vector::from((1, -1))
+    );
+    assert_eq!(motion.remove_component_along(&Vector::default()), motion);
+}
+
+#[test]
+fn test_remove_component_along_long_direction() {
+    let motion = Vector::from((30, 40));
+    assert_eq!(
+        motion.remove_component_along(&Vector::from((100, 0))),
+        Vector::from((0, 40))
+    );
+    assert_eq!(
+        motion.remove_component_along(&Vector::from((0, -60))),
+        Vector::from((30, 0))
+    );
+    let sliding = Vector::from((80, 20)).remove_component_along(&Vector::from((60, 60)));
+    assert_eq!(sliding, Vector::from((30, -30)));
+}
+
+#[test]
+fn test_approx_eq() {
+    let epsilon = Fp::from(0.01);
+    let third = Fp::one() / Fp::from(3);
+    let v = Vector::new(third * Fp::from(3), Fp::from(2));
+    assert!(v.approx_eq(&Vector::from((1, 2)), epsilon));
+    assert!(!v.approx_eq(&Vector::from((1.1, 2.0)), epsilon));
+
+    let rect = Rect::new(Vector::from((1, 1)) * third, Vector::from((10, 10)) * third);
+    assert!(rect.approx_eq(&Rect::from((0.333, 0.333, 3.333, 3.333)), epsilon));
+    assert!(!rect.approx_eq(&Rect::from((0.333, 0.333, 3.333, 3.4)), epsilon));
+    assert!(rect.approx_eq(&rect, Fp::zero()));
+}
+
+#[test]
+fn test_hadamard() {
+    let a = Vector::from((2, 3));
+    let b = Vector::from((4, -5));
+    assert_eq!(a.hadamard(&b), Vector::from((8, -15)));
+    assert_eq!(a * b, a.hadamard(&b));
+    assert_eq!(a.dot(&b), Fp::from(-7));
+}
+
+#[test]
+fn test_center() {
+    let rect = Rect::from((0, 0, 10, 10));
+    assert_eq!(rect.center(), Vector::from((5, 5)));
+
+    let centered = rect.with_center(Vector::default());
+    assert_eq!(centered.pos, Vector::from((-5, -5)));
+    assert_eq!(centered.size, rect.size);
+    assert_eq!(centered.center(), Vector::default());
+}
+
+#[test]
+fn test_split_at() {
+    let rect = Rect::from((0, 0, 10, 8));
+    assert_eq!(
+        rect.split_at(Vector::from((3, 6))),
+        [
+            Rect::from((0, 0, 3, 6)),
+            Rect::from((3, 0, 7, 6)),
+            Rect::from((0, 6, 3, 2)),
+            Rect::from((3, 6, 7, 2)),
+        ]
+    );
+
+    let clamped = rect.split_at(Vector::from((-5, 20)));
+    assert_eq!(clamped[0], Rect::from((0, 0, 0, 8)));
+    assert_eq!(clamped[1], Rect::from((0, 0, 10, 8)));
+    assert_eq!(clamped[2], Rect::from((0, 8, 0, 0)));
+    assert_eq!(clamped[3], Rect::from((0, 8, 10, 0)));
+}
+
+#[test]
+fn test_from_center() {
+    let center = Vector::from((5, 5));
+    let rect = Rect::from_center(center, Vector::from((10, 10)));
+    assert_eq!(
+        rect,
+        Rect::new(Vector::from((0, 0)), Vector::from((10, 10)))
+    );
+    assert_eq!(rect.center(), center);
+}
+
+#[test]
+fn test_from_heading_speed() {
+    let epsilon = Fp::from(0.01);
+    let speed = Fp::from(10);
+    let north = Vector::from_heading_speed(Fp::zero(), speed);
+    let east = Vector::from_heading_speed(Fp::from(90), speed);
+    let south = Vector::from_heading_speed(Fp::from(180), speed);
+    let west = Vector::from_heading_speed(Fp::from(270), speed);
+    assert!(north.approx_eq(&Vector::from((0, 10)), epsilon));
+    assert!(east.approx_eq(&Vector::from((10, 0)), epsilon));
+    assert!(south.approx_eq(&Vector::from((0, -10)), epsilon));
+    assert!(west.approx_eq(&Vector::from((-10, 0)), epsilon));
+
+    let north_east = Vector::from_heading_speed(Fp::from(45), speed);
+    assert!(north_east.x > Fp::zero() && north_east.y > Fp::zero());
+    assert!((north_east.len() - speed).abs() < epsilon);
+}
+
+#[test]
+fn test_corners() {
+    let rect = Rect::from((0, 0, 1, 1));
+    assert_eq!(
+        rect.corners(),
+        [
+            Vector::from((0, 0)),
+            Vector::from((1, 0)),
+            Vector::from((1, 1)),
+            Vector::from((0, 1)),
+        ]
+    );
+}
+
+#[test]
+fn test_is_overlapping() {
+    let rect = Rect::from((0, 0, 10, 10));
+    let cases = [
+        (Rect::from((20, 0, 5, 5)), false),
+        (Rect::from((0, 20, 5, 5)), false),
+        (Rect::from((10, 0, 5, 5)), false),
+        (Rect::from((-5, 0, 5, 5)), false),
+        (Rect::from((0, 10, 5, 5)), false),
+        (Rect::from((10, 10, 5, 5)), false),
+        (Rect::from((5, 5, 10, 10)), true),
+        (Rect::from((-5, -5, 10, 10)), true),
+        (Rect::from((2, 2, 3, 3)), true),
+        (Rect::from((-5, -5, 20, 20)), true),
+    ];
+    for (other, expected) in cases {
+        assert_eq!(rect.is_overlapping(other), expected, "{other:?}");
+        assert_eq!(other.is_overlapping(rect), expected, "{other:?}");
+        assert_eq!(rect.intersection(&other).is_some(), expected, "{other:?}");
+    }
+}
+
+#[test]
+fn test_contains_rect_edges() {
+    let outer = Rect::from((0, 0, 10, 10));
+    assert!(outer.contains_rect(&outer));
+    assert!(outer.contains_rect(&Rect::from((5, 2, 5, 3))));
+    assert!(outer.contains_rect(&Rect::from((2, 5, 3, 5))));
+    assert!(outer.contains_rect(&Rect::from((2, 2, 3, 3))));
+    assert!(!outer.contains_rect(&Rect::from((6, 2, 5, 3))));
+    assert!(!outer.contains_rect(&Rect::from((-1, 2, 5, 3))));
+    assert!(!outer.contains_rect(&Rect::from((-1, -1, 12, 12))));
+}
+
+#[test]
+fn test_closest_point() {
+    let rect = Rect::from((0, 0, 10, 10));
+    assert_eq!(
+        rect.closest_point(&Vector::from((15, 5))),
+        Vector::from((10, 5))
+    );
+    assert_eq!(
+        rect.closest_point(&Vector::from((5, 5))),
+        Vector::from((5, 5))
+    );
+    assert_eq!(
+        rect.closest_point(&Vector::from((-3, 12))),
+        Vector::from((0, 10))
+    );
+}
+
+#[test]
+fn test_from_points() {
+    assert_eq!(Rect::from_points(&[]), None);
+    assert_eq!(
+        Rect::from_points(&[Vector::from((3, -2))]),
+        Some(Rect::from((3, -2, 0, 0)))
+    );
+    assert_eq!(
+        Rect::from_points(&[Vector::from((4, 5)), Vector::from((1, 1))]),
+        Some(Rect::from((1, 1, 3, 4)))
+    );
+    let points = [
+        Vector::from((2, 3)),
+        Vector::from((-1, 7)),
+        Vector::from((5, -4)),
+        Vector::from((0, 0)),
+    ];
+    assert_eq!(
+        Rect::from_points(&points),
+        Some(Rect::from((-1, -4, 6, 11)))
+    );
+}
+
+#[test]
+fn test_from_corners() {
+    let a = Vector::from((4, 1));
+    let b = Vector::from((-2, 6));
+    let expected = Rect::from((-2, 1, 6, 5));
+    assert_eq!(Rect::from_corners(a, b), expected);
+    assert_eq!(Rect::from_corners(b, a), expected);
+    assert!(Rect::try_new(expected.pos, expected.size).is_some());
+
+    assert_eq!(
+        Rect::from_min_max(Vector::from((1, 2)), Vector::from((4, 6))),
+        Rect::from((1, 2, 3, 4))
+    );
+}
+
+#[test]
+fn test_aspect_ratio() {
+    let wide = Rect::from((0, 0, 16, 9));
+    assert_eq!(wide.aspect_ratio(), Some(Fp::from(16) / Fp::from(9)));
+    assert_eq!(Rect::from((0, 0, 4, 2)).aspect_ratio(), Some(Fp::from(2)));
+    assert_eq!(Rect::from((0, 0, 16, 0)).aspect_ratio(), None);
+}
+
+#[test]
+fn test_normalized() {
+    let flipped = Rect::from((10, 10, -4, -4));
+    assert!(!flipped.contains_point(&Vector::from((8, 8))));
+
+    let normalized = flipped.normalized();
+    assert_eq!(normalized, Rect::from((6, 6, 4, 4)));
+    assert!(normalized.contains_point(&Vector::from((8, 8))));
+    assert!(!normalized.contains_point(&Vector::from((10, 10))));
+
+    assert_eq!(
+        Rect::from((1, 5, 3, -2)).normalized(),
+        Rect::from((1, 3, 3, 2))
+    );
+    let valid = Rect::from((1, 2, 3, 4));
+    assert_eq!(valid.normalized(), valid);
+}
+
+#[test]
+fn test_inset() {
+    let rect = Rect::from((0, 0, 10, 10));
+    let inset = rect.inset(Fp::from(1), Fp::from(2), Fp::from(3), Fp::from(4));
+    assert_eq!(inset.left(), Fp::from(1));
+    assert_eq!(inset.right(), Fp::from(8));
+    assert_eq!(inset.top(), Fp::from(7));
+    assert_eq!(inset.bottom(), Fp::from(4));
+
+    let grown = rect.inset(Fp::from(-1), Fp::zero(), Fp::from(-2), Fp::zero());
+    assert_eq!(grown, Rect::from((-1, 0, 11, 12)));
+
+    let collapsed = rect.inset(Fp::from(8), Fp::from(8), Fp::from(1), Fp::from(1));
+    assert_eq!(collapsed.size, Vector::from((0, 8)));
+    assert_eq!(collapsed.pos, Vector::from((8, 1)));
+}
+
+#[test]
+fn test_ray_display() {
+    let origin = Vector::from((1, 2));
+    let direction = Vector::from((0, -1));
+    let ray = Ray::new(origin, direction);
+    assert_eq!(ray.to_string(), format!("({origin}, {direction})"));
+}
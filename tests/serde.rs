@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/fixed32-math-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+#![cfg(feature = "serde")]
+
+use fixed32::Fp;
+
+use fixed32_math::{Rect, Vector};
+
+#[test]
+fn vector_json_is_named_struct() {
+    let vector = Vector::from((1.5, -2.0));
+    let json = serde_json::to_string(&vector).unwrap();
+    assert_eq!(json, r#"{"x":1.5,"y":-2.0}"#);
+    assert_eq!(serde_json::from_str::<Vector>(&json).unwrap(), vector);
+}
+
+#[test]
+fn rect_json_is_named_struct() {
+    let rect = Rect::from((1, 2, 3, 4));
+    let json = serde_json::to_string(&rect).unwrap();
+    assert_eq!(
+        json,
+        r#"{"pos":{"x":1.0,"y":2.0},"size":{"x":3.0,"y":4.0}}"#
+    );
+    assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), rect);
+}
+
+#[test]
+fn vector_bincode_is_compact() {
+    let vector = Vector::new(Fp::from_raw(-1), Fp::from_raw(i32::MAX));
+    let bytes = bincode::serialize(&vector).unwrap();
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(bincode::deserialize::<Vector>(&bytes).unwrap(), vector);
+}
+
+#[test]
+fn rect_bincode_is_compact() {
+    let rect = Rect::from((-1.25, 2.5, 300.0, 0.001));
+    let bytes = bincode::serialize(&rect).unwrap();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bincode::deserialize::<Rect>(&bytes).unwrap(), rect);
+}
+
+#[test]
+fn json_round_trip_is_lossless() {
+    let vector = Vector::new(Fp::from_raw(1), Fp::from_raw(-123_456_789));
+    let json = serde_json::to_string(&vector).unwrap();
+    assert_eq!(serde_json::from_str::<Vector>(&json).unwrap(), vector);
+}
+
+#[test]
+fn json_out_of_range_is_rejected() {
+    assert!(serde_json::from_str::<Vector>(r#"{"x":1e12,"y":0.0}"#).is_err());
+}
+
+#[test]
+fn json_round_trips_vectors_and_rects() {
+    let vectors = [
+        Vector::default(),
+        Vector::from((3, -4)),
+        Vector::from((0.001, 1234.5)),
+        Vector::new(Fp::from_raw(i32::MIN), Fp::from_raw(i32::MAX)),
+    ];
+    for vector in vectors {
+        let json = serde_json::to_string(&vector).unwrap();
+        assert_eq!(serde_json::from_str::<Vector>(&json).unwrap(), vector);
+    }
+
+    let rects = [
+        Rect::default(),
+        Rect::from((-1, 2, 3, 4)),
+        Rect::from((0.1, -0.2, 0.3, 1000.0)),
+    ];
+    for rect in rects {
+        let json = serde_json::to_string(&rect).unwrap();
+        assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), rect);
+    }
+}